@@ -0,0 +1,134 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::puzzle::PuzzlePart;
+
+/// Name of the answer sheet, relative to the crate root
+pub const ANSWERS_FILE: &str = "answers.tsv";
+
+const HEADER: &str = "# year\tday\tpart\tanswer";
+
+pub type AnswerKey = (usize, usize, PuzzlePart);
+
+/// The verified answer for each puzzle part, stored as tab-separated lines.
+/// Lines starting with `#` are comments.
+#[derive(Debug)]
+pub struct AnswerSheet {
+    path: PathBuf,
+    answers: BTreeMap<AnswerKey, String>,
+}
+
+impl AnswerSheet {
+    /// Open the answer sheet at the given path. A missing file is an empty
+    /// sheet.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let answers = match fs::read_to_string(path) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(parse_line)
+                .collect::<io::Result<_>>()?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            answers,
+        })
+    }
+
+    pub fn get(&self, year: usize, day: usize, part: PuzzlePart) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(|s| s.as_str())
+    }
+
+    /// Iterate over the verified answers, ordered by year, day, and part
+    pub fn iter(&self) -> impl Iterator<Item = (&AnswerKey, &str)> {
+        self.answers
+            .iter()
+            .map(|(key, answer)| (key, answer.as_str()))
+    }
+
+    /// Record the verified answer for a puzzle part, replacing any previous
+    /// answer, and rewrite the sheet
+    pub fn record(
+        &mut self,
+        year: usize,
+        day: usize,
+        part: PuzzlePart,
+        answer: &str,
+    ) -> io::Result<()> {
+        self.answers.insert((year, day, part), answer.to_string());
+
+        let mut contents = format!("{}\n", HEADER);
+        for ((year, day, part), answer) in &self.answers {
+            contents += &format!("{}\t{}\t{}\t{}\n", year, day, part, answer);
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, contents)
+    }
+}
+
+fn parse_line(line: &str) -> io::Result<(AnswerKey, String)> {
+    let malformed = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Malformed answer '{}'", line),
+        )
+    };
+
+    let fields: Vec<_> = line.splitn(4, '\t').collect();
+    if fields.len() != 4 {
+        return Err(malformed());
+    }
+
+    let year = fields[0].parse().map_err(|_| malformed())?;
+    let day = fields[1].parse().map_err(|_| malformed())?;
+    let part = fields[2].parse().map_err(|_| malformed())?;
+    Ok(((year, day, part), fields[3].to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::core::{puzzle::PuzzlePart, util::test_dir};
+
+    use super::AnswerSheet;
+
+    #[test]
+    fn it_records_and_replaces_answers() {
+        let dir = test_dir("answers-record");
+        let path = dir.join("answers.tsv");
+
+        let mut sheet = AnswerSheet::open(&path).unwrap();
+        sheet.record(2020, 3, PuzzlePart::Two, "336").unwrap();
+        sheet.record(2020, 1, PuzzlePart::One, "1").unwrap();
+        sheet.record(2020, 1, PuzzlePart::One, "514579").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# year\tday\tpart\tanswer\n2020\t1\t1\t514579\n2020\t3\t2\t336\n"
+        );
+
+        let sheet = AnswerSheet::open(&path).unwrap();
+        assert_eq!(sheet.get(2020, 1, PuzzlePart::One), Some("514579"));
+        assert_eq!(sheet.get(2020, 3, PuzzlePart::Two), Some("336"));
+        assert_eq!(sheet.get(2020, 3, PuzzlePart::One), None);
+    }
+
+    #[test]
+    fn it_rejects_malformed_lines() {
+        let dir = test_dir("answers-malformed");
+        let path = dir.join("answers.tsv");
+
+        fs::write(&path, "2020\t1\t3\t42\n").unwrap();
+        assert!(AnswerSheet::open(&path).is_err());
+    }
+}
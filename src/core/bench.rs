@@ -0,0 +1,230 @@
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+
+use super::{
+    puzzle::{Puzzle, PuzzleError, PuzzlePart},
+    solver::Solver,
+    util::JsonString,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BenchFormat {
+    Json,
+    Markdown,
+}
+
+#[derive(Debug, Error)]
+#[error("Benchmark format must be json or markdown, got '{0}'")]
+pub struct ParseBenchFormatError(String);
+
+impl FromStr for BenchFormat {
+    type Err = ParseBenchFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(BenchFormat::Json),
+            "markdown" | "md" => Ok(BenchFormat::Markdown),
+            _ => Err(ParseBenchFormatError(s.to_string())),
+        }
+    }
+}
+
+/// A single headless run of a solver
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    /// Wall time spent building the solver from the input and stepping it to
+    /// completion
+    pub elapsed: Duration,
+    pub steps: usize,
+    pub solution: Option<String>,
+}
+
+/// Build a solver for the given part and step it to completion, counting the
+/// steps it takes
+pub fn measure(
+    puzzle: &dyn Puzzle,
+    part: PuzzlePart,
+    input: &str,
+) -> Result<Measurement, PuzzleError> {
+    let start = Instant::now();
+
+    let mut solver = puzzle.solver(part, input)?;
+    let mut steps = 0;
+    while !solver.is_done() {
+        solver.step();
+        steps += 1;
+    }
+    let solution = solver.solution();
+
+    Ok(Measurement {
+        elapsed: start.elapsed(),
+        steps,
+        solution,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchResult {
+    pub year: usize,
+    pub day: usize,
+    pub part: PuzzlePart,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    pub steps: usize,
+    pub solution: Option<String>,
+}
+
+/// Solve the given part the given number of times, which must be at least one
+pub fn bench(
+    puzzle: &dyn Puzzle,
+    part: PuzzlePart,
+    input: &str,
+    runs: usize,
+) -> Result<BenchResult, PuzzleError> {
+    assert!(runs > 0, "Cannot benchmark fewer than one run");
+
+    let measurements = (0..runs)
+        .map(|_| measure(puzzle, part, input))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut times: Vec<_> = measurements.iter().map(|m| m.elapsed).collect();
+    times.sort();
+
+    let last = measurements.last().unwrap();
+    Ok(BenchResult {
+        year: puzzle.get_year(),
+        day: puzzle.get_day(),
+        part,
+        runs,
+        min: times[0],
+        median: median(&times),
+        max: times[times.len() - 1],
+        steps: last.steps,
+        solution: last.solution.clone(),
+    })
+}
+
+fn median(sorted: &[Duration]) -> Duration {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+pub fn format_results(results: &[BenchResult], format: BenchFormat) -> String {
+    match format {
+        BenchFormat::Json => to_json(results),
+        BenchFormat::Markdown => to_markdown(results),
+    }
+}
+
+/// Format the results as a JSON array with times in microseconds
+pub fn to_json(results: &[BenchResult]) -> String {
+    let objects: Vec<_> = results
+        .iter()
+        .map(|r| {
+            let solution = match &r.solution {
+                Some(solution) => JsonString(solution).to_string(),
+                None => "null".to_string(),
+            };
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"runs\": {}, \"min_us\": {}, \"median_us\": {}, \"max_us\": {}, \"steps\": {}, \"solution\": {}}}",
+                r.year,
+                r.day,
+                r.part,
+                r.runs,
+                r.min.as_micros(),
+                r.median.as_micros(),
+                r.max.as_micros(),
+                r.steps,
+                solution
+            )
+        })
+        .collect();
+
+    if objects.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", objects.join(",\n"))
+    }
+}
+
+pub fn to_markdown(results: &[BenchResult]) -> String {
+    let mut lines = vec![
+        "| Year | Day | Part | Runs | Min | Median | Max | Steps |".to_string(),
+        "|-----:|----:|-----:|-----:|----:|-------:|----:|------:|".to_string(),
+    ];
+
+    lines.extend(results.iter().map(|r| {
+        format!(
+            "| {} | {} | {} | {} | {:?} | {:?} | {:?} | {} |",
+            r.year, r.day, r.part, r.runs, r.min, r.median, r.max, r.steps
+        )
+    }));
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::core::puzzle::PuzzlePart;
+    use crate::day01::Day01;
+
+    use super::{bench, median, to_json, to_markdown, BenchResult};
+
+    const INPUT: &str = "1721\n979\n366\n299\n675\n1456\n";
+
+    fn result() -> BenchResult {
+        BenchResult {
+            year: 2020,
+            day: 1,
+            part: PuzzlePart::Two,
+            runs: 3,
+            min: Duration::from_micros(10),
+            median: Duration::from_micros(15),
+            max: Duration::from_micros(40),
+            steps: 7,
+            solution: Some("say \"hi\"".to_string()),
+        }
+    }
+
+    #[test]
+    fn it_benchmarks_a_puzzle() {
+        let result = bench(&Day01::new(), PuzzlePart::One, INPUT, 3).unwrap();
+        assert_eq!(result.runs, 3);
+        assert!(result.min <= result.median && result.median <= result.max);
+        assert!(result.steps > 0);
+        assert_eq!(result.solution, Some("514579".to_string()));
+    }
+
+    #[test]
+    fn it_takes_the_median() {
+        let ms = Duration::from_millis;
+        assert_eq!(median(&[ms(1), ms(2), ms(9)]), ms(2));
+        assert_eq!(median(&[ms(1), ms(2), ms(4), ms(9)]), ms(3));
+    }
+
+    #[test]
+    fn it_formats_results() {
+        assert_eq!(
+            to_json(&[result()]),
+            "[\n  {\"year\": 2020, \"day\": 1, \"part\": 2, \"runs\": 3, \"min_us\": 10, \"median_us\": 15, \"max_us\": 40, \"steps\": 7, \"solution\": \"say \\\"hi\\\"\"}\n]"
+        );
+        assert_eq!(to_json(&[]), "[]");
+
+        let markdown = to_markdown(&[result()]);
+        assert_eq!(
+            markdown.lines().last(),
+            Some("| 2020 | 1 | 2 | 3 | 10µs | 15µs | 40µs | 7 |")
+        );
+    }
+}
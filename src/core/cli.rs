@@ -0,0 +1,828 @@
+use std::{io, path::PathBuf, time::Duration};
+
+use thiserror::Error;
+
+use super::{
+    answers::AnswerSheet,
+    bench::{bench, BenchFormat, BenchResult},
+    input::{InputError, InputProvider},
+    puzzle::{BoxedSolver, Puzzle, PuzzleError, PuzzlePart, PuzzleRegistry},
+    recording::{record, RecordedFrame, RecordingFormat, RecordingOptions},
+    remote::{Client, HttpBackend, RemoteError},
+    solver::solve_reporting,
+    submissions::Verdict,
+};
+
+pub const USAGE: &str = "Usage:
+    advent [--input-dir <DIR>]            Browse puzzles in the interactive hub
+    advent run [OPTIONS] --day <DAY> --part <PART>
+                                          Solve a puzzle and print its solution
+    advent fetch [--year <YEAR>] [--input-dir <DIR>] --day <DAY>
+                                          Download a puzzle input into the input
+                                          directory unless it is already there
+    advent submit [OPTIONS] --day <DAY> --part <PART> [--answer <ANSWER>]
+                                          Submit an answer, solving the puzzle
+                                          first if no answer is given
+    advent record [--year <YEAR>] --day <DAY> --part <PART> --answer <ANSWER>
+                                          Save an answer that the puzzle site
+                                          accepted to the answer sheet
+    advent bench [--year <YEAR>] [--day <DAY>] [--part <PART>] [--runs <N>]
+                 [--format <json|markdown>] [--input-dir <DIR>]
+                                          Time every matching puzzle part over N
+                                          runs, defaulting to 10 runs and a
+                                          markdown table
+    advent capture [OPTIONS] --day <DAY> --part <PART> --output <FILE>
+                   [--format <cast|text>] [--size <WxH>] [--every <N>]
+                   [--fps <N>] [--max-frames <N>]
+                                          Record a solver's visualization as an
+                                          asciinema cast, the default, or as
+                                          plain text frames
+
+Options:
+    --year <YEAR>      Event year, defaults to the latest registered year
+    --input <FILE>     Read the puzzle input from FILE, or from stdin if FILE is -
+    --input-dir <DIR>  Read puzzle inputs from DIR, defaults to $ADVENT_INPUT_DIR
+                       or ./input
+
+The fetch and submit commands read the session token from $ADVENT_SESSION.";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Command {
+    Hub {
+        input: Option<InputProvider>,
+    },
+    Run {
+        year: Option<usize>,
+        day: usize,
+        part: PuzzlePart,
+        input: Option<InputProvider>,
+    },
+    Fetch {
+        year: Option<usize>,
+        day: usize,
+        input_dir: Option<PathBuf>,
+    },
+    Submit {
+        year: Option<usize>,
+        day: usize,
+        part: PuzzlePart,
+        answer: Option<String>,
+        input_dir: Option<PathBuf>,
+    },
+    Record {
+        year: Option<usize>,
+        day: usize,
+        part: PuzzlePart,
+        answer: String,
+    },
+    Bench {
+        year: Option<usize>,
+        day: Option<usize>,
+        part: Option<PuzzlePart>,
+        runs: usize,
+        format: BenchFormat,
+        input: Option<InputProvider>,
+    },
+    Capture {
+        year: Option<usize>,
+        day: usize,
+        part: PuzzlePart,
+        input: Option<InputProvider>,
+        output: PathBuf,
+        format: RecordingFormat,
+        options: RecordingOptions,
+    },
+}
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum CliError {
+    #[error("Unknown command '{0}'")]
+    UnknownCommand(String),
+
+    #[error("Unknown argument '{0}'")]
+    UnknownArgument(String),
+
+    #[error("Missing value for argument '{0}'")]
+    MissingValue(String),
+
+    #[error("Missing required argument '{0}'")]
+    MissingArgument(&'static str),
+
+    #[error("Invalid value '{1}' for argument '{0}'")]
+    InvalidValue(&'static str, String),
+
+    #[error("Arguments '{0}' and '{1}' cannot be used together")]
+    Conflict(&'static str, &'static str),
+}
+
+pub type Result<T> = std::result::Result<T, CliError>;
+
+const DEFAULT_BENCH_RUNS: usize = 10;
+
+/// How often a headless solver's progress is reported
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Error)]
+pub enum RunError {
+    #[error("No puzzles are registered")]
+    NoPuzzles,
+
+    #[error("No puzzle is registered for {0} day {1}")]
+    NotRegistered(usize, usize),
+
+    #[error("{0} day {1} part {2} is not implemented")]
+    NotImplemented(usize, usize, PuzzlePart),
+
+    #[error("{0} day {1} part {2} did not find a solution")]
+    NoSolution(usize, usize, PuzzlePart),
+
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    #[error("{}", .1.describe(.0.as_deref()))]
+    Puzzle(Option<PathBuf>, #[source] PuzzleError),
+
+    #[error(transparent)]
+    Remote(#[from] RemoteError),
+
+    #[error("Could not update the answer sheet")]
+    Answers(#[source] io::Error),
+}
+
+/// Parse the command line arguments, excluding the program name
+pub fn parse_args<I>(args: I) -> Result<Command>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+
+    let command = match args.peek().map(|arg| arg.as_str()) {
+        None => return Ok(Command::Hub { input: None }),
+        Some(arg) if arg.starts_with("--") => "hub".to_string(),
+        Some(_) => args.next().unwrap(),
+    };
+
+    match command.as_str() {
+        "hub" => parse_hub(Flags::parse(args, &["--input-dir"])?),
+        "run" => parse_run(Flags::parse(
+            args,
+            &["--year", "--day", "--part", "--input", "--input-dir"],
+        )?),
+        "fetch" => parse_fetch(Flags::parse(args, &["--year", "--day", "--input-dir"])?),
+        "submit" => parse_submit(Flags::parse(
+            args,
+            &["--year", "--day", "--part", "--answer", "--input-dir"],
+        )?),
+        "record" => parse_record(Flags::parse(
+            args,
+            &["--year", "--day", "--part", "--answer"],
+        )?),
+        "bench" => parse_bench(Flags::parse(
+            args,
+            &[
+                "--year",
+                "--day",
+                "--part",
+                "--runs",
+                "--format",
+                "--input-dir",
+            ],
+        )?),
+        "capture" => parse_capture(Flags::parse(
+            args,
+            &[
+                "--year",
+                "--day",
+                "--part",
+                "--input",
+                "--input-dir",
+                "--output",
+                "--format",
+                "--size",
+                "--every",
+                "--fps",
+                "--max-frames",
+            ],
+        )?),
+        _ => Err(CliError::UnknownCommand(command)),
+    }
+}
+
+fn parse_hub(mut flags: Flags) -> Result<Command> {
+    let input = flags
+        .take("--input-dir")
+        .map(|dir| InputProvider::Root(PathBuf::from(dir)));
+    Ok(Command::Hub { input })
+}
+
+fn parse_run(mut flags: Flags) -> Result<Command> {
+    let year = flags.number("--year")?;
+    let day = flags.required_number("--day")?;
+    let part = flags.part()?;
+    let input = flags.input()?;
+
+    Ok(Command::Run {
+        year,
+        day,
+        part,
+        input,
+    })
+}
+
+fn parse_fetch(mut flags: Flags) -> Result<Command> {
+    Ok(Command::Fetch {
+        year: flags.number("--year")?,
+        day: flags.required_number("--day")?,
+        input_dir: flags.take("--input-dir").map(PathBuf::from),
+    })
+}
+
+fn parse_submit(mut flags: Flags) -> Result<Command> {
+    Ok(Command::Submit {
+        year: flags.number("--year")?,
+        day: flags.required_number("--day")?,
+        part: flags.part()?,
+        answer: flags.take("--answer"),
+        input_dir: flags.take("--input-dir").map(PathBuf::from),
+    })
+}
+
+fn parse_record(mut flags: Flags) -> Result<Command> {
+    Ok(Command::Record {
+        year: flags.number("--year")?,
+        day: flags.required_number("--day")?,
+        part: flags.part()?,
+        answer: flags
+            .take("--answer")
+            .ok_or(CliError::MissingArgument("--answer"))?,
+    })
+}
+
+fn parse_bench(mut flags: Flags) -> Result<Command> {
+    let format = match flags.take("--format") {
+        Some(value) => value
+            .parse()
+            .map_err(|_| CliError::InvalidValue("--format", value))?,
+        None => BenchFormat::Markdown,
+    };
+
+    Ok(Command::Bench {
+        year: flags.number("--year")?,
+        day: flags.number("--day")?,
+        part: flags.optional_part()?,
+        runs: flags.number("--runs")?.unwrap_or(DEFAULT_BENCH_RUNS),
+        format,
+        input: flags.input()?,
+    })
+}
+
+fn parse_capture(mut flags: Flags) -> Result<Command> {
+    let format = match flags.take("--format") {
+        Some(value) => value
+            .parse()
+            .map_err(|_| CliError::InvalidValue("--format", value))?,
+        None => RecordingFormat::Cast,
+    };
+
+    let defaults = RecordingOptions::default();
+    let size = match flags.take("--size") {
+        Some(value) => parse_size(&value).ok_or(CliError::InvalidValue("--size", value))?,
+        None => defaults.size,
+    };
+    let options = RecordingOptions {
+        size,
+        every: flags.number("--every")?.unwrap_or(defaults.every),
+        fps: flags.number("--fps")?.unwrap_or(defaults.fps),
+        max_frames: flags.number("--max-frames")?.unwrap_or(defaults.max_frames),
+    };
+
+    Ok(Command::Capture {
+        year: flags.number("--year")?,
+        day: flags.required_number("--day")?,
+        part: flags.part()?,
+        input: flags.input()?,
+        output: flags
+            .take("--output")
+            .map(PathBuf::from)
+            .ok_or(CliError::MissingArgument("--output"))?,
+        format,
+        options,
+    })
+}
+
+/// Parse a size given as `<WIDTH>x<HEIGHT>`
+fn parse_size(value: &str) -> Option<cursive::Vec2> {
+    let (width, height) = value.split_once('x')?;
+    match (width.parse().ok()?, height.parse().ok()?) {
+        (0, _) | (_, 0) => None,
+        (width, height) => Some(cursive::Vec2::new(width, height)),
+    }
+}
+
+/// The `--flag value` pairs given to a command. Later values override
+/// earlier ones.
+struct Flags {
+    values: Vec<(&'static str, String)>,
+}
+
+impl Flags {
+    fn parse<I>(mut args: I, allowed: &[&'static str]) -> Result<Self>
+    where
+        I: Iterator<Item = String>,
+    {
+        let mut values = Vec::new();
+
+        while let Some(arg) = args.next() {
+            let flag = allowed
+                .iter()
+                .find(|&&flag| flag == arg)
+                .ok_or_else(|| CliError::UnknownArgument(arg.clone()))?;
+            let value = args.next().ok_or(CliError::MissingValue(arg))?;
+            values.push((*flag, value));
+        }
+
+        Ok(Self { values })
+    }
+
+    fn take(&mut self, flag: &str) -> Option<String> {
+        let index = self.values.iter().rposition(|(f, _)| *f == flag)?;
+        Some(self.values.remove(index).1)
+    }
+
+    fn number(&mut self, flag: &'static str) -> Result<Option<usize>> {
+        self.take(flag)
+            .map(|value| match value.parse() {
+                Ok(n) if n > 0 => Ok(n),
+                _ => Err(CliError::InvalidValue(flag, value)),
+            })
+            .transpose()
+    }
+
+    fn required_number(&mut self, flag: &'static str) -> Result<usize> {
+        self.number(flag)?.ok_or(CliError::MissingArgument(flag))
+    }
+
+    fn part(&mut self) -> Result<PuzzlePart> {
+        self.optional_part()?
+            .ok_or(CliError::MissingArgument("--part"))
+    }
+
+    fn optional_part(&mut self) -> Result<Option<PuzzlePart>> {
+        self.take("--part")
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| CliError::InvalidValue("--part", value))
+            })
+            .transpose()
+    }
+
+    fn input(&mut self) -> Result<Option<InputProvider>> {
+        match (self.take("--input"), self.take("--input-dir")) {
+            (Some(_), Some(_)) => Err(CliError::Conflict("--input", "--input-dir")),
+            (Some(file), None) if file == "-" => Ok(Some(InputProvider::Stdin)),
+            (Some(file), None) => Ok(Some(InputProvider::File(PathBuf::from(file)))),
+            (None, Some(dir)) => Ok(Some(InputProvider::Root(PathBuf::from(dir)))),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// Look up a registered puzzle. The latest registered year is used if no year
+/// is given.
+fn find_puzzle(
+    registry: &PuzzleRegistry,
+    year: Option<usize>,
+    day: usize,
+) -> std::result::Result<&dyn Puzzle, RunError> {
+    let year = year
+        .or_else(|| registry.latest_year())
+        .ok_or(RunError::NoPuzzles)?;
+    registry
+        .get(year, day)
+        .as_deref()
+        .ok_or(RunError::NotRegistered(year, day))
+}
+
+/// Load the puzzle's input and build a solver for the given part from it
+fn build_solver(
+    puzzle: &dyn Puzzle,
+    input: &InputProvider,
+    part: PuzzlePart,
+) -> std::result::Result<BoxedSolver, RunError> {
+    let contents = input.load(puzzle)?;
+    puzzle
+        .solver(part, &contents)
+        .map_err(|err| RunError::Puzzle(input.locate(puzzle), err))
+}
+
+fn solve(
+    puzzle: &dyn Puzzle,
+    input: &InputProvider,
+    part: PuzzlePart,
+    report: &mut dyn FnMut(&str),
+) -> std::result::Result<String, RunError> {
+    let (year, day) = (puzzle.get_year(), puzzle.get_day());
+    if !puzzle.is_implemented(part) {
+        return Err(RunError::NotImplemented(year, day, part));
+    }
+
+    let mut solver = build_solver(puzzle, input, part)?;
+    solve_reporting(&mut solver, PROGRESS_INTERVAL, report)
+        .ok_or(RunError::NoSolution(year, day, part))
+}
+
+/// Solve a registered puzzle without a UI. Days are numbered from 1, and the
+/// latest registered year is used if no year is given.
+pub fn run_headless(
+    registry: &PuzzleRegistry,
+    input: &InputProvider,
+    year: Option<usize>,
+    day: usize,
+    part: PuzzlePart,
+) -> std::result::Result<String, RunError> {
+    run_headless_reporting(registry, input, year, day, part, &mut |_| ())
+}
+
+/// Solve a registered puzzle without a UI like `run_headless`, calling
+/// `report` with the solver's progress about once a second
+pub fn run_headless_reporting(
+    registry: &PuzzleRegistry,
+    input: &InputProvider,
+    year: Option<usize>,
+    day: usize,
+    part: PuzzlePart,
+    report: &mut dyn FnMut(&str),
+) -> std::result::Result<String, RunError> {
+    let puzzle = find_puzzle(registry, year, day)?;
+    solve(puzzle, input, part, report)
+}
+
+/// Download a registered puzzle's input into the client's input directory,
+/// returning the path it was saved to
+pub fn run_fetch<B: HttpBackend>(
+    registry: &PuzzleRegistry,
+    client: &Client<B>,
+    year: Option<usize>,
+    day: usize,
+) -> std::result::Result<PathBuf, RunError> {
+    let puzzle = find_puzzle(registry, year, day)?;
+    Ok(client.fetch_input(puzzle)?)
+}
+
+/// Submit an answer for a registered puzzle. If no answer is given, the
+/// puzzle's input is fetched and solved first.
+pub fn run_submit<B: HttpBackend>(
+    registry: &PuzzleRegistry,
+    client: &Client<B>,
+    year: Option<usize>,
+    day: usize,
+    part: PuzzlePart,
+    answer: Option<String>,
+) -> std::result::Result<(String, Verdict), RunError> {
+    let puzzle = find_puzzle(registry, year, day)?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            client.fetch_input(puzzle)?;
+            let input = InputProvider::Root(client.root().to_path_buf());
+            solve(puzzle, &input, part, &mut |_| ())?
+        }
+    };
+
+    let verdict = client.submit(puzzle, part, &answer)?;
+    Ok((answer, verdict))
+}
+
+/// Save the verified answer for a registered puzzle to the answer sheet. The
+/// answer is never taken from the solver itself, since the answer sheet is
+/// what the solvers are checked against.
+pub fn run_record(
+    registry: &PuzzleRegistry,
+    sheet: &mut AnswerSheet,
+    year: Option<usize>,
+    day: usize,
+    part: PuzzlePart,
+    answer: String,
+) -> std::result::Result<String, RunError> {
+    let puzzle = find_puzzle(registry, year, day)?;
+
+    sheet
+        .record(puzzle.get_year(), puzzle.get_day(), part, &answer)
+        .map_err(RunError::Answers)?;
+    Ok(answer)
+}
+
+/// Benchmark every implemented part of the registered puzzles matching the
+/// given filters. Parts whose input is missing are skipped and returned along
+/// with the results.
+pub fn run_bench(
+    registry: &PuzzleRegistry,
+    input: &InputProvider,
+    year: Option<usize>,
+    day: Option<usize>,
+    part: Option<PuzzlePart>,
+    runs: usize,
+) -> std::result::Result<(Vec<BenchResult>, Vec<InputError>), RunError> {
+    let mut results = Vec::new();
+    let mut skipped = Vec::new();
+
+    let years = match year {
+        Some(year) => vec![year],
+        None => registry.get_years(),
+    };
+    let puzzles = years
+        .into_iter()
+        .filter_map(|year| registry.get_puzzles(year))
+        .flatten()
+        .filter_map(|slot| slot.as_deref())
+        .filter(|puzzle| day.is_none_or(|day| puzzle.get_day() == day));
+
+    for puzzle in puzzles {
+        let parts = [PuzzlePart::One, PuzzlePart::Two];
+        let parts = parts
+            .iter()
+            .cloned()
+            .filter(|&p| part.is_none_or(|part| p == part))
+            .filter(|&p| puzzle.is_implemented(p));
+
+        for part in parts {
+            let contents = match input.load(puzzle) {
+                Ok(contents) => contents,
+                Err(err @ InputError::NotFound(_)) => {
+                    skipped.push(err);
+                    break;
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            let result = bench(puzzle, part, &contents, runs)
+                .map_err(|err| RunError::Puzzle(input.locate(puzzle), err))?;
+            results.push(result);
+        }
+    }
+
+    Ok((results, skipped))
+}
+
+/// Record a registered puzzle's visualization off-screen, returning a title
+/// for the recording along with its frames
+pub fn run_capture(
+    registry: &PuzzleRegistry,
+    input: &InputProvider,
+    year: Option<usize>,
+    day: usize,
+    part: PuzzlePart,
+    options: &RecordingOptions,
+) -> std::result::Result<(String, Vec<RecordedFrame>), RunError> {
+    let puzzle = find_puzzle(registry, year, day)?;
+    let (year, day) = (puzzle.get_year(), puzzle.get_day());
+    if !puzzle.is_implemented(part) {
+        return Err(RunError::NotImplemented(year, day, part));
+    }
+
+    let mut solver = build_solver(puzzle, input, part)?;
+    let frames = record(&mut solver, options);
+
+    let title = format!(
+        "{} Day {}: {} (Part {})",
+        year,
+        day,
+        puzzle.get_title(),
+        part
+    );
+    Ok((title, frames))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::core::{
+        bench::BenchFormat,
+        input::InputProvider,
+        puzzle::{PuzzlePart, PuzzleRegistry},
+        recording::{RecordingFormat, RecordingOptions},
+        util::test_dir,
+    };
+
+    use super::{parse_args, run_headless, CliError, Command, RunError};
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn it_defaults_to_the_hub() {
+        assert_eq!(parse_args(args("")), Ok(Command::Hub { input: None }));
+        assert_eq!(
+            parse_args(args("--input-dir inputs")),
+            Ok(Command::Hub {
+                input: Some(InputProvider::Root(PathBuf::from("inputs")))
+            })
+        );
+    }
+
+    #[test]
+    fn it_parses_the_run_command() {
+        assert_eq!(
+            parse_args(args("run --day 3 --part 2")),
+            Ok(Command::Run {
+                year: None,
+                day: 3,
+                part: PuzzlePart::Two,
+                input: None,
+            })
+        );
+        assert_eq!(
+            parse_args(args("run --part 1 --day 12 --year 2020")),
+            Ok(Command::Run {
+                year: Some(2020),
+                day: 12,
+                part: PuzzlePart::One,
+                input: None,
+            })
+        );
+    }
+
+    #[test]
+    fn it_parses_input_arguments() {
+        let input_of = |s: &str| match parse_args(args(s)) {
+            Ok(Command::Run { input, .. }) => input,
+            other => panic!("Expected a run command, got {:?}", other),
+        };
+
+        assert_eq!(
+            input_of("run --day 1 --part 1 --input report.txt"),
+            Some(InputProvider::File(PathBuf::from("report.txt")))
+        );
+        assert_eq!(
+            input_of("run --day 1 --part 1 --input -"),
+            Some(InputProvider::Stdin)
+        );
+        assert_eq!(
+            input_of("run --day 1 --part 1 --input-dir inputs"),
+            Some(InputProvider::Root(PathBuf::from("inputs")))
+        );
+        assert_eq!(
+            parse_args(args("run --day 1 --part 1 --input - --input-dir inputs")),
+            Err(CliError::Conflict("--input", "--input-dir"))
+        );
+    }
+
+    #[test]
+    fn it_parses_remote_commands() {
+        assert_eq!(
+            parse_args(args("fetch --day 4 --input-dir inputs")),
+            Ok(Command::Fetch {
+                year: None,
+                day: 4,
+                input_dir: Some(PathBuf::from("inputs")),
+            })
+        );
+        assert_eq!(
+            parse_args(args("submit --year 2020 --day 4 --part 1 --answer 256")),
+            Ok(Command::Submit {
+                year: Some(2020),
+                day: 4,
+                part: PuzzlePart::One,
+                answer: Some("256".to_string()),
+                input_dir: None,
+            })
+        );
+        assert_eq!(
+            parse_args(args("record --day 4 --part 2 --answer 17")),
+            Ok(Command::Record {
+                year: None,
+                day: 4,
+                part: PuzzlePart::Two,
+                answer: "17".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_args(args("record --day 4 --part 2")),
+            Err(CliError::MissingArgument("--answer"))
+        );
+        assert_eq!(
+            parse_args(args("fetch --day 4 --part 1")),
+            Err(CliError::UnknownArgument("--part".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_parses_the_bench_command() {
+        assert_eq!(
+            parse_args(args("bench")),
+            Ok(Command::Bench {
+                year: None,
+                day: None,
+                part: None,
+                runs: 10,
+                format: BenchFormat::Markdown,
+                input: None,
+            })
+        );
+        assert_eq!(
+            parse_args(args("bench --day 9 --part 2 --runs 50 --format json")),
+            Ok(Command::Bench {
+                year: None,
+                day: Some(9),
+                part: Some(PuzzlePart::Two),
+                runs: 50,
+                format: BenchFormat::Json,
+                input: None,
+            })
+        );
+        assert_eq!(
+            parse_args(args("bench --format csv")),
+            Err(CliError::InvalidValue("--format", "csv".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_parses_the_capture_command() {
+        assert_eq!(
+            parse_args(args("capture --day 3 --part 1 --output day03.cast")),
+            Ok(Command::Capture {
+                year: None,
+                day: 3,
+                part: PuzzlePart::One,
+                input: None,
+                output: PathBuf::from("day03.cast"),
+                format: RecordingFormat::Cast,
+                options: RecordingOptions::default(),
+            })
+        );
+
+        match parse_args(args(
+            "capture --day 3 --part 1 --output out.txt --format text --size 40x10 --every 5",
+        )) {
+            Ok(Command::Capture {
+                format, options, ..
+            }) => {
+                assert_eq!(format, RecordingFormat::Text);
+                assert_eq!(options.size, cursive::Vec2::new(40, 10));
+                assert_eq!(options.every, 5);
+            }
+            other => panic!("Expected a capture command, got {:?}", other),
+        }
+
+        assert_eq!(
+            parse_args(args("capture --day 3 --part 1")),
+            Err(CliError::MissingArgument("--output"))
+        );
+        assert_eq!(
+            parse_args(args("capture --day 3 --part 1 --output a --size 40")),
+            Err(CliError::InvalidValue("--size", "40".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_rejects_bad_arguments() {
+        assert_eq!(
+            parse_args(args("walk")),
+            Err(CliError::UnknownCommand("walk".to_string()))
+        );
+        assert_eq!(
+            parse_args(args("run --day 3")),
+            Err(CliError::MissingArgument("--part"))
+        );
+        assert_eq!(
+            parse_args(args("run --day 0 --part 1")),
+            Err(CliError::InvalidValue("--day", "0".to_string()))
+        );
+        assert_eq!(
+            parse_args(args("run --day 3 --part 3")),
+            Err(CliError::InvalidValue("--part", "3".to_string()))
+        );
+        assert_eq!(
+            parse_args(args("run --year twenty --day 1 --part 1")),
+            Err(CliError::InvalidValue("--year", "twenty".to_string()))
+        );
+        assert_eq!(
+            parse_args(args("run --day")),
+            Err(CliError::MissingValue("--day".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_reports_where_the_input_could_not_be_parsed() {
+        let dir = test_dir("cli-parse-error");
+        let path = dir.join("passwords.txt");
+        fs::write(&path, "1-3 a: abcde\n1-3 b cdefg\n").unwrap();
+
+        let registry = PuzzleRegistry::with_puzzles(25, crate::puzzles()).unwrap();
+        let input = InputProvider::File(path.clone());
+        let result = run_headless(&registry, &input, Some(2020), 2, PuzzlePart::One);
+
+        assert!(matches!(result, Err(RunError::Puzzle(_, _))));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "{}:2: The password entry did not match the regex",
+                path.display()
+            )
+        );
+    }
+}
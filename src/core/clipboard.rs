@@ -0,0 +1,64 @@
+use std::{
+    fs::OpenOptions,
+    io::{self, Write},
+};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Ask the terminal to copy text to the system clipboard with an OSC 52
+/// escape sequence. The sequence is written to the controlling terminal
+/// rather than stdout, which belongs to the UI. Terminals that do not support
+/// OSC 52 ignore it without saying so, so success only means that the request
+/// was sent.
+pub fn send_to_clipboard(text: &str) -> io::Result<()> {
+    let mut tty = OpenOptions::new().write(true).open("/dev/tty")?;
+    tty.write_all(osc52(text).as_bytes())?;
+    tty.flush()
+}
+
+/// The OSC 52 escape sequence that sets the clipboard to the given text
+pub fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).cloned().unwrap_or(0),
+            chunk.get(2).cloned().unwrap_or(0),
+        ];
+        let n = (b[0] as usize) << 16 | (b[1] as usize) << 8 | b[2] as usize;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (n >> (18 - 6 * i)) & 0x3f;
+                encoded.push(BASE64_ALPHABET[index] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{base64, osc52};
+
+    #[test]
+    fn it_encodes_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"514579"), "NTE0NTc5");
+    }
+
+    #[test]
+    fn it_builds_the_escape_sequence() {
+        assert_eq!(osc52("241861950"), "\x1b]52;c;MjQxODYxOTUw\x07");
+    }
+}
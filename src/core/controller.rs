@@ -7,12 +7,15 @@ pub trait Controller {
     fn process_events(&mut self, c: Rc<RefCell<Cursive>>) -> bool;
 }
 
-pub fn run<C, E>(controller: C, c: Rc<RefCell<Cursive>>)
+/// Run the controller until it stops, then hand it back
+pub fn run<C, E>(controller: C, c: Rc<RefCell<Cursive>>) -> C
 where
     C: Controller,
     E: 'static,
 {
-    EventLoop::<C, E>::new(controller, c).run()
+    let mut event_loop = EventLoop::<C, E>::new(controller, c);
+    event_loop.run();
+    event_loop.into_controller()
 }
 
 pub fn emit<E: 'static>(c: &mut Cursive, event: E) {
@@ -47,6 +50,10 @@ where
         }
     }
 
+    pub fn into_controller(self) -> C {
+        self.controller
+    }
+
     pub fn run(&mut self) {
         self.controller.show(Rc::clone(&self.cursive));
 
@@ -1,3 +1,4 @@
+use std::fmt::Display;
 use std::fs::File;
 use std::io;
 use std::io::prelude::*;
@@ -11,21 +12,64 @@ pub enum ParseIoError {
     #[error("Could not read file")]
     Io(#[from] io::Error),
 
-    #[error("Could not parse file")]
-    Parse,
+    /// A line could not be parsed. Lines are numbered from 1.
+    #[error("Could not parse line {0}: {1}")]
+    Parse(usize, String),
 }
 
 pub type ParseIoResult<T> = Result<T, ParseIoError>;
 
 /// Read each line of the file at the given path and convert it to type T
-pub fn parse_lines<T: FromStr>(path: &str) -> ParseIoResult<Vec<T>> {
-    use ParseIoError::*;
-
+pub fn parse_lines<T>(path: &str) -> ParseIoResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
     let file = File::open(path)?;
     let reader = BufReader::new(file);
 
     reader
         .lines()
-        .map(|line| line?.parse().map_err(|_| Parse))
+        .enumerate()
+        .map(|(i, line)| parse_line(i, &line?))
+        .collect()
+}
+
+/// Convert each line of the given string to type T
+pub fn parse_str_lines<T>(contents: &str) -> ParseIoResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(i, line))
         .collect()
 }
+
+fn parse_line<T>(index: usize, line: &str) -> ParseIoResult<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    line.parse()
+        .map_err(|err: T::Err| ParseIoError::Parse(index + 1, err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_str_lines, ParseIoError};
+
+    #[test]
+    fn it_reports_the_line_that_could_not_be_parsed() {
+        let result = parse_str_lines::<i64>("1721\n979\nabc\n299");
+        match result {
+            Err(ParseIoError::Parse(line, message)) => {
+                assert_eq!(line, 3);
+                assert_eq!(message, "invalid digit found in string");
+            }
+            _ => panic!("Expected a parse error, got {:?}", result),
+        }
+    }
+}
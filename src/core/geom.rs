@@ -0,0 +1,295 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position on a grid, where x increases to the right and y increases
+/// downwards
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+/// The offset between two points
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Vec2<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Vec2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Point<isize> {
+    /// Get the point with unsigned coordinates, if neither is negative
+    pub fn to_unsigned(self) -> Option<Point<usize>> {
+        if self.x < 0 || self.y < 0 {
+            None
+        } else {
+            Some(Point::new(self.x as usize, self.y as usize))
+        }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        (self - other).manhattan_length()
+    }
+}
+
+impl Point<usize> {
+    pub fn to_signed(self) -> Point<isize> {
+        Point::new(self.x as isize, self.y as isize)
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Vec2<isize> {
+    pub fn manhattan_length(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Rotate a quarter turn clockwise, as seen with y increasing downwards
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotate a quarter turn counter-clockwise, as seen with y increasing
+    /// downwards
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(vec: Vec2<T>) -> Self {
+        (vec.x, vec.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}, {}>", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vec2<T>> for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vec2<T>) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vec2<T>> for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vec2<T>) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Self) -> Vec2<T> {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vec2<T>> for Point<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vec2<T>> for Point<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// One of the four orthogonal directions on a grid
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(-1)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turn by the given number of quarter turns, where positive turns are
+    /// clockwise
+    pub fn rotate(self, quarter_turns: isize) -> Self {
+        let index = self as isize + quarter_turns;
+        Self::ALL[index.rem_euclid(4) as usize]
+    }
+
+    /// The offset of a single step in this direction
+    pub fn offset(self) -> Vec2<isize> {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(-1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Point, Vec2};
+
+    #[test]
+    fn it_does_arithmetic() {
+        let p = Point::new(3, -2);
+        let v = Vec2::new(-1, 4);
+
+        assert_eq!(p + v, Point::new(2, 2));
+        assert_eq!(p - v, Point::new(4, -6));
+        assert_eq!(Point::new(2, 2) - p, v);
+        assert_eq!(v * 3, Vec2::new(-3, 12));
+        assert_eq!(-v, Vec2::new(1, -4));
+
+        let mut q = p;
+        q += v;
+        q -= v * 2;
+        assert_eq!(q, Point::new(4, -6));
+
+        let u: Point<usize> = (3, 1).into();
+        assert_eq!(u + Vec2::new(3, 1), Point::new(6, 2));
+        assert_eq!(<(usize, usize)>::from(u), (3, 1));
+    }
+
+    #[test]
+    fn it_measures_manhattan_distance() {
+        assert_eq!(
+            Point::<isize>::new(3, -2).manhattan_distance(Point::new(-1, 1)),
+            7
+        );
+        assert_eq!(Vec2::new(-17, 8).manhattan_length(), 25);
+        assert_eq!(
+            Point::<usize>::new(1, 5).manhattan_distance(Point::new(4, 2)),
+            6
+        );
+    }
+
+    #[test]
+    fn it_converts_between_signed_and_unsigned() {
+        assert_eq!(Point::new(2, 0).to_unsigned(), Some(Point::new(2, 0)));
+        assert_eq!(Point::new(2, -1).to_unsigned(), None);
+        assert_eq!(Point::<usize>::new(4, 5).to_signed(), Point::new(4, 5));
+    }
+
+    #[test]
+    fn it_turns_and_rotates() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Direction::Down.rotate(-7), Direction::Left);
+
+        for &direction in Direction::ALL.iter() {
+            let offset = direction.offset();
+            assert_eq!(offset.rotate_right(), direction.turn_right().offset());
+            assert_eq!(offset.rotate_left(), direction.turn_left().offset());
+        }
+        assert_eq!(Vec2::new(10, 4).rotate_right(), Vec2::new(-4, 10));
+    }
+}
@@ -1,20 +1,69 @@
-use std::convert::TryFrom;
+use std::{
+    convert::TryFrom,
+    ops::{Index, IndexMut},
+};
 use thiserror::Error;
 
+use super::geom::Point;
+
 #[derive(Debug, Error)]
 pub enum GridError {
     #[error("Could not parse grid with 0 width or height")]
     EmptyGrid,
 
+    /// Holds the index of the first row whose length differs from the first
     #[error("Could not parse grid with different length rows")]
-    RowsNotSameLength,
+    RowsNotSameLength(usize),
+
+    #[error("Position ({0}, {1}) is outside of the grid")]
+    OutOfBounds(usize, usize),
 }
 
 pub type Result<T> = std::result::Result<T, GridError>;
 
+/// The offsets of the orthogonally adjacent cells, in order of up, left,
+/// right, down
+pub const VON_NEUMANN_OFFSETS: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// The offsets of the orthogonally and diagonally adjacent cells, in order of
+/// up-left, up, up-right, left, right, down-left, down, down-right
+pub const MOORE_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Which cells around a position count as its neighbors
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Neighborhood {
+    /// The 4 orthogonally adjacent cells
+    VonNeumann,
+    /// The 8 orthogonally and diagonally adjacent cells
+    Moore,
+    /// The cells at the given `(dx, dy)` offsets
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighborhood {
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        match self {
+            Neighborhood::VonNeumann => &VON_NEUMANN_OFFSETS,
+            Neighborhood::Moore => &MOORE_OFFSETS,
+            Neighborhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// A rectangular grid of cells stored contiguously in row-major order.
+/// Positions are `(x, y)` pairs, where `(0, 0)` is the top left.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Grid<T: Clone> {
-    data: Vec<Vec<T>>,
+    data: Vec<T>,
     width: usize,
     height: usize,
 }
@@ -24,10 +73,8 @@ where
     T: Clone,
 {
     pub fn new(width: usize, height: usize, default: T) -> Self {
-        let row: Vec<_> = std::iter::repeat(default).take(width).collect();
-        let data = std::iter::repeat(row).take(height).collect();
         Self {
-            data,
+            data: vec![default; width * height],
             width,
             height,
         }
@@ -41,24 +88,19 @@ where
         }
 
         let width = data[0].len();
-        if !data.iter().map(|row| row.len()).all(|w| w == width) {
-            return Err(RowsNotSameLength);
+        if let Some(row) = data.iter().position(|row| row.len() != width) {
+            return Err(RowsNotSameLength(row));
         }
 
-        let width = data[0].len();
         let height = data.len();
 
         Ok(Self {
-            data,
+            data: data.into_iter().flatten().collect(),
             width,
             height,
         })
     }
 
-    pub fn data(&self) -> &Vec<Vec<T>> {
-        &self.data
-    }
-
     pub fn width(&self) -> usize {
         self.width
     }
@@ -68,66 +110,221 @@ where
     }
 
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
-        if !self.is_in_bounds(x, y) {
-            return None;
-        }
+        self.index_of(x, y).map(|i| &self.data[i])
+    }
 
-        Some(&self.data[y][x])
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(move |i| &mut self.data[i])
     }
 
-    pub fn set(&mut self, x: usize, y: usize, value: T) {
-        if self.is_in_bounds(x, y) {
-            self.data[y][x] = value;
-        }
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Result<()> {
+        let cell = self.get_mut(x, y).ok_or(GridError::OutOfBounds(x, y))?;
+        *cell = value;
+        Ok(())
     }
 
     pub fn is_in_bounds(&self, x: usize, y: usize) -> bool {
         x < self.width && y < self.height
     }
 
+    /// Get the cell at a point, or `None` if the point is negative or out of
+    /// bounds
+    pub fn get_point(&self, point: Point<isize>) -> Option<&T> {
+        let point = point.to_unsigned()?;
+        self.get(point.x, point.y)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point<isize>) -> Option<&mut T> {
+        let point = point.to_unsigned()?;
+        self.get_mut(point.x, point.y)
+    }
+
+    pub fn contains_point(&self, point: Point<isize>) -> bool {
+        point
+            .to_unsigned()
+            .is_some_and(|point| self.is_in_bounds(point.x, point.y))
+    }
+
+    /// Iterate over the cells in row-major order
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
+
+    /// Iterate over the position of each cell in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterate over each cell along with its position in row-major order
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    /// Iterate over the rows from top to bottom. A grid without columns has
+    /// no rows to iterate over.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.width.max(1))
+    }
+
+    /// Iterate over the cells of a column from top to bottom. The column is
+    /// empty if it is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let width = self.width;
+        let height = if x < width { self.height } else { 0 };
+        (0..height).map(move |y| &self.data[y * width + x])
+    }
+
+    /// Iterate over the columns from left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
     /// Get adjacent neighbors for the given position. Neighbors are returned in
     /// order of up-left, up, up-right, left, right, down-left, down,
     /// down-right.
     pub fn neighbors(&self, x: usize, y: usize) -> Option<Vec<&T>> {
+        let neighbors = self.neighbors_in(x, y, &Neighborhood::Moore)?;
+        Some(neighbors.into_iter().map(|(_, cell)| cell).collect())
+    }
+
+    /// Get the neighbors in the given neighborhood along with their positions,
+    /// in the order of the neighborhood's offsets. Offsets that fall outside
+    /// of the grid are skipped.
+    pub fn neighbors_in(
+        &self,
+        x: usize,
+        y: usize,
+        neighborhood: &Neighborhood,
+    ) -> Option<Vec<((usize, usize), &T)>> {
         if !self.is_in_bounds(x, y) {
             return None;
         }
 
-        let mut neighbors = Vec::new();
-
-        let up = y > 0;
-        let down = y + 1 < self.height;
-        let left = x > 0;
-        let right = x + 1 < self.width;
+        let neighbors = neighborhood
+            .offsets()
+            .iter()
+            .filter_map(|&offset| self.offset(x, y, offset))
+            .map(|pos| (pos, &self[pos]))
+            .collect();
+        Some(neighbors)
+    }
 
-        if up && left {
-            neighbors.push(&self.data[y - 1][x - 1]);
-        }
-        if up {
-            neighbors.push(&self.data[y - 1][x]);
-        }
-        if up && right {
-            neighbors.push(&self.data[y - 1][x + 1])
+    /// Get the position at an offset from the given position, if it is inside
+    /// of the grid
+    pub fn offset(&self, x: usize, y: usize, (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = (x as isize).checked_add(dx)?;
+        let y = (y as isize).checked_add(dy)?;
+        if x < 0 || y < 0 || !self.is_in_bounds(x as usize, y as usize) {
+            return None;
         }
+        Some((x as usize, y as usize))
+    }
 
-        if left {
-            neighbors.push(&self.data[y][x - 1]);
+    /// Walk from the given position in steps of `direction` until reaching a
+    /// cell that matches the predicate, returning it along with its position.
+    /// The starting cell is not tested, and nothing is returned if the walk
+    /// leaves the grid first.
+    pub fn cast_ray<P>(
+        &self,
+        x: usize,
+        y: usize,
+        direction: (isize, isize),
+        predicate: P,
+    ) -> Option<((usize, usize), &T)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        if direction == (0, 0) {
+            return None;
         }
-        if right {
-            neighbors.push(&self.data[y][x + 1]);
+
+        let mut pos = (x, y);
+        loop {
+            pos = self.offset(pos.0, pos.1, direction)?;
+            let cell = &self[pos];
+            if predicate(cell) {
+                return Some((pos, cell));
+            }
         }
+    }
+
+    /// Cast a ray in the direction of each offset in the neighborhood,
+    /// returning the first matching cell for each ray that finds one
+    pub fn cast_rays<P>(
+        &self,
+        x: usize,
+        y: usize,
+        neighborhood: &Neighborhood,
+        predicate: P,
+    ) -> Vec<((usize, usize), &T)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        neighborhood
+            .offsets()
+            .iter()
+            .filter_map(|&direction| self.cast_ray(x, y, direction, &predicate))
+            .collect()
+    }
 
-        if down && left {
-            neighbors.push(&self.data[y + 1][x - 1]);
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if self.is_in_bounds(x, y) {
+            Some(y * self.width + x)
+        } else {
+            None
         }
-        if down {
-            neighbors.push(&self.data[y + 1][x]);
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T>
+where
+    T: Clone,
+{
+    type Output = T;
+
+    /// Get the cell at an `(x, y)` position, panicking if it is out of bounds
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.index_of(x, y) {
+            Some(i) => &self.data[i],
+            None => panic!("{}", GridError::OutOfBounds(x, y)),
         }
-        if down && right {
-            neighbors.push(&self.data[y + 1][x + 1]);
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T>
+where
+    T: Clone,
+{
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.index_of(x, y) {
+            Some(i) => &mut self.data[i],
+            None => panic!("{}", GridError::OutOfBounds(x, y)),
         }
+    }
+}
 
-        Some(neighbors)
+impl<T> Index<Point<usize>> for Grid<T>
+where
+    T: Clone,
+{
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T>
+where
+    T: Clone,
+{
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        &mut self[(point.x, point.y)]
     }
 }
 
@@ -159,7 +356,8 @@ impl std::str::FromStr for Grid<char> {
 
 #[cfg(test)]
 mod tests {
-    use super::Grid;
+    use super::{Grid, GridError, Neighborhood};
+    use crate::core::geom::{Direction, Point};
     use std::convert::TryFrom;
 
     #[test]
@@ -186,4 +384,113 @@ mod tests {
         assert_eq!(grid.neighbors(2, 2), Some(vec![&'e', &'f', &'h']));
         assert_eq!(grid.neighbors(3, 3), None);
     }
+
+    #[test]
+    fn it_indexes_and_sets_cells() {
+        let mut grid = Grid::try_from(vec!["ab", "cd", "ef"]).unwrap();
+        assert_eq!(grid[(1, 0)], 'b');
+        assert_eq!(grid[(0, 2)], 'e');
+
+        grid[(0, 1)] = 'x';
+        assert!(grid.set(1, 2, 'y').is_ok());
+        assert!(matches!(
+            grid.set(2, 0, 'z'),
+            Err(GridError::OutOfBounds(2, 0))
+        ));
+        assert_eq!(grid, Grid::try_from(vec!["ab", "xd", "ey"]).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Position (0, 3) is outside of the grid")]
+    fn it_panics_when_indexing_out_of_bounds() {
+        let grid = Grid::new(2, 3, 0);
+        let _ = grid[(0, 3)];
+    }
+
+    #[test]
+    fn it_iterates_over_rows_columns_and_cells() {
+        let grid = Grid::try_from(vec!["abc", "def"]).unwrap();
+
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.column(3).count(), 0);
+
+        let cells: String = grid.cells().collect();
+        assert_eq!(cells, "abcdef");
+
+        let enumerated: Vec<_> = grid.enumerate().skip(2).take(2).collect();
+        assert_eq!(enumerated, vec![((2, 0), &'c'), ((0, 1), &'d')]);
+    }
+
+    #[test]
+    fn it_iterates_over_a_grid_without_columns() {
+        let grid = Grid::new(0, 3, '.');
+
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.cells().count(), 0);
+    }
+
+    #[test]
+    fn it_gets_neighbors_with_positions() {
+        let grid = Grid::try_from(vec!["abc", "def", "ghi"]).unwrap();
+
+        assert_eq!(
+            grid.neighbors_in(0, 1, &Neighborhood::VonNeumann),
+            Some(vec![((0, 0), &'a'), ((1, 1), &'e'), ((0, 2), &'g')])
+        );
+        assert_eq!(
+            grid.neighbors_in(1, 1, &Neighborhood::Custom(vec![(1, 1), (-2, 0), (0, -1)])),
+            Some(vec![((2, 2), &'i'), ((1, 0), &'b')])
+        );
+        assert_eq!(
+            grid.neighbors_in(1, 1, &Neighborhood::Moore).unwrap().len(),
+            8
+        );
+        assert_eq!(grid.neighbors_in(3, 0, &Neighborhood::Moore), None);
+    }
+
+    #[test]
+    fn it_casts_rays_until_a_cell_matches() {
+        let grid = Grid::try_from(vec![".L.L", "....", "#..L", "...."]).unwrap();
+        let is_seat = |&c: &char| c != '.';
+
+        assert_eq!(grid.cast_ray(0, 0, (1, 0), is_seat), Some(((1, 0), &'L')));
+        assert_eq!(grid.cast_ray(0, 0, (1, 1), is_seat), None);
+        assert_eq!(grid.cast_ray(0, 0, (0, 1), is_seat), Some(((0, 2), &'#')));
+        assert_eq!(grid.cast_ray(3, 3, (-1, -1), is_seat), None);
+        assert_eq!(grid.cast_ray(0, 0, (0, 0), is_seat), None);
+
+        assert_eq!(
+            grid.cast_rays(1, 2, &Neighborhood::Moore, is_seat),
+            vec![
+                ((1, 0), &'L'),
+                ((3, 0), &'L'),
+                ((0, 2), &'#'),
+                ((3, 2), &'L')
+            ]
+        );
+    }
+
+    #[test]
+    fn it_looks_up_points() {
+        let mut grid = Grid::try_from(vec!["ab", "cd"]).unwrap();
+
+        assert_eq!(grid.get_point(Point::new(1, 0)), Some(&'b'));
+        assert_eq!(grid.get_point(Point::new(-1, 0)), None);
+        assert_eq!(grid.get_point(Point::new(0, 2)), None);
+        assert!(grid.contains_point(Point::new(1, 1)));
+        assert!(!grid.contains_point(Point::new(1, -1)));
+
+        let start = Point::new(0, 1);
+        let up = start + Direction::Up.offset();
+        *grid.get_point_mut(up).unwrap() = 'x';
+        assert_eq!(grid[Point::new(0, 0)], 'x');
+
+        grid[Point::new(1, 1)] = 'y';
+        assert_eq!(grid.get(1, 1), Some(&'y'));
+    }
 }
@@ -0,0 +1,162 @@
+use std::collections::VecDeque;
+
+use super::solver::Solver;
+
+/// The most snapshots kept by default. Older snapshots are dropped first.
+pub const DEFAULT_CAPACITY: usize = 10_000;
+
+/// A timeline of solver snapshots, one per step, that can be scrubbed through
+/// while the live solver stays where it is
+pub struct History<T> {
+    snapshots: VecDeque<Box<dyn Solver<T>>>,
+    capacity: usize,
+
+    /// The step number of the oldest snapshot
+    first_step: usize,
+
+    /// The index of the snapshot being viewed, or None when viewing the live
+    /// solver
+    cursor: Option<usize>,
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl<T> History<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            snapshots: VecDeque::new(),
+            capacity,
+            first_step: 0,
+            cursor: None,
+        }
+    }
+
+    /// Record a snapshot of the live solver before it takes its next step
+    pub fn record(&mut self, snapshot: Box<dyn Solver<T>>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+            self.first_step += 1;
+            self.cursor = self.cursor.map(|cursor| cursor.saturating_sub(1));
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.cursor.is_none()
+    }
+
+    /// The snapshot being viewed, if the live solver is not being viewed
+    pub fn viewing(&self) -> Option<&dyn Solver<T>> {
+        self.cursor.map(|cursor| self.snapshots[cursor].as_ref())
+    }
+
+    /// The step number being viewed, where the live solver is at the step
+    /// after the newest snapshot
+    pub fn position(&self) -> usize {
+        self.first_step + self.cursor.unwrap_or(self.snapshots.len())
+    }
+
+    /// The step number of the live solver
+    pub fn live_position(&self) -> usize {
+        self.first_step + self.snapshots.len()
+    }
+
+    /// Move back through the timeline, stopping at the oldest snapshot
+    pub fn back(&mut self, steps: usize) {
+        if self.snapshots.is_empty() {
+            return;
+        }
+
+        let current = self.cursor.unwrap_or(self.snapshots.len());
+        self.cursor = Some(current.saturating_sub(steps));
+    }
+
+    /// Move forward through the timeline. Returns the number of steps left
+    /// over after reaching the live solver, which the live solver should take.
+    pub fn forward(&mut self, steps: usize) -> usize {
+        match self.cursor {
+            None => steps,
+            Some(cursor) => {
+                let target = cursor + steps;
+                if target < self.snapshots.len() {
+                    self.cursor = Some(target);
+                    0
+                } else {
+                    self.cursor = None;
+                    target - self.snapshots.len()
+                }
+            }
+        }
+    }
+
+    pub fn to_start(&mut self) {
+        self.back(self.snapshots.len());
+    }
+
+    pub fn to_live(&mut self) {
+        self.cursor = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::solver::Solver;
+    use crate::day01::part1::Solve2Sum;
+
+    use super::History;
+
+    fn record_steps(history: &mut History<i64>, solver: &mut Solve2Sum, steps: usize) {
+        for _ in 0..steps {
+            history.record(solver.snapshot().unwrap());
+            solver.step();
+        }
+    }
+
+    #[test]
+    fn it_scrubs_through_snapshots() {
+        let mut solver = Solve2Sum::new(vec![1, 2, 3, 4, 5, 6], 100);
+        let mut history = History::default();
+        record_steps(&mut history, &mut solver, 4);
+        assert!(history.is_live());
+        assert_eq!(history.position(), 4);
+
+        history.back(1);
+        assert_eq!(history.position(), 3);
+        assert_eq!(history.viewing().unwrap().steps_taken(), Some(3));
+
+        history.to_start();
+        assert_eq!(history.position(), 0);
+        assert_eq!(history.viewing().unwrap().steps_taken(), Some(0));
+
+        assert_eq!(history.forward(2), 0);
+        assert_eq!(history.position(), 2);
+
+        assert_eq!(history.forward(5), 3);
+        assert!(history.is_live());
+        assert_eq!(history.position(), 4);
+    }
+
+    #[test]
+    fn it_drops_the_oldest_snapshots() {
+        let mut solver = Solve2Sum::new(vec![1, 2, 3, 4, 5, 6], 100);
+        let mut history = History::new(3);
+        record_steps(&mut history, &mut solver, 5);
+
+        history.to_start();
+        assert_eq!(history.position(), 2);
+        assert_eq!(history.viewing().unwrap().steps_taken(), Some(2));
+        assert_eq!(history.live_position(), 5);
+    }
+}
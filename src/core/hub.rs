@@ -1,58 +1,219 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use cursive::{
-    views::{Dialog, SelectView},
+    event::{Event, EventResult, EventTrigger},
+    traits::Scrollable,
+    views::{Dialog, OnEventView, SelectView, TextView},
     Cursive,
 };
 
 use super::{
     controller::{emit, take_events, Controller},
-    puzzle::{PuzzlePart, PuzzleRegistry},
+    input::InputProvider,
+    puzzle::{Puzzle, PuzzlePart, PuzzleRegistry, PuzzleSlots},
+    results::{format_timestamp, ResultLog, SolveResult},
+    solver::{solve, SolverOutcome},
 };
 
 pub enum HubEvent {
-    DaySelected(usize),
-    PuzzleSelected(usize, PuzzlePart),
+    YearSelected(usize),
+    DaySelected(usize, usize),
+    PuzzleSelected(usize, usize, PuzzlePart),
+    BothPartsSelected(usize, usize),
+    HistorySelected(usize, usize),
+    /// Show or hide the days that are not implemented for a year
+    FilterToggled(usize),
 }
 
 pub struct Hub {
     registry: PuzzleRegistry,
+    input: InputProvider,
+    results: ResultLog,
+    hide_unimplemented: bool,
+    startup_error: Option<String>,
+}
+
+impl Hub {
+    pub fn new(registry: PuzzleRegistry, input: InputProvider, results: ResultLog) -> Self {
+        Self {
+            registry,
+            input,
+            results,
+            hide_unimplemented: false,
+            startup_error: None,
+        }
+    }
+
+    /// Show an error over the list of years when the hub opens
+    pub fn with_startup_error(mut self, message: String) -> Self {
+        self.startup_error = Some(message);
+        self
+    }
 }
 
 impl Hub {
-    pub fn new(registry: PuzzleRegistry) -> Self {
-        Self { registry }
+    fn build_day_view(&self, year: usize) -> Dialog {
+        let puzzles = self.registry.get_puzzles(year).unwrap();
+        build_day_view(year, puzzles, &self.results, self.hide_unimplemented)
+    }
+
+    /// Run the solvers for the given parts of a puzzle one after the other,
+    /// skipping parts that are not implemented
+    fn run_parts(
+        &mut self,
+        c: Rc<RefCell<Cursive>>,
+        year: usize,
+        day: usize,
+        parts: &[PuzzlePart],
+    ) {
+        let puzzle = self.registry.get(year, day).as_ref().unwrap();
+        let requested = parts;
+        let mut parts: VecDeque<_> = parts
+            .iter()
+            .cloned()
+            .filter(|&part| puzzle.is_implemented(part))
+            .collect();
+        let mut part = match parts.pop_front() {
+            Some(part) => part,
+            None => {
+                let message = match requested {
+                    [part] => format!(
+                        "Part {} of day {} ({}) has not been implemented yet.",
+                        part,
+                        day,
+                        puzzle.get_title()
+                    ),
+                    _ => format!(
+                        "Neither part of day {} ({}) has been implemented yet.",
+                        day,
+                        puzzle.get_title()
+                    ),
+                };
+                let view = build_error_view("Not implemented", message);
+                c.borrow_mut().add_layer(view);
+                return;
+            }
+        };
+
+        let input = match self.input.load(puzzle.as_ref()) {
+            Ok(input) => input,
+            Err(err) => {
+                let view = build_error_view("Could not load input", err.to_string());
+                c.borrow_mut().add_layer(view);
+                return;
+            }
+        };
+
+        c.borrow_mut().pop_layer();
+        c.borrow_mut().pop_layer();
+
+        // The title and message of an error to show once back at the list of
+        // days
+        let mut error = None;
+        loop {
+            let other_part = Some(part.other()).filter(|&p| puzzle.is_implemented(p));
+            let solver = match puzzle.solver(part, &input) {
+                Ok(solver) => solver,
+                Err(err) => {
+                    let path = self.input.locate(puzzle.as_ref());
+                    error = Some(("Could not solve puzzle", err.describe(path.as_deref())));
+                    break;
+                }
+            };
+            let exit = solve(solver, Rc::clone(&c), other_part);
+
+            if let Some(answer) = exit.solution {
+                let result = SolveResult::now(year, day, part, answer, exit.duration, &input);
+                if let Err(err) = self.results.record(result) {
+                    error = Some(("Could not save result", err.to_string()));
+                }
+            }
+
+            match exit.outcome {
+                SolverOutcome::Back => match parts.pop_front() {
+                    Some(next) => part = next,
+                    None => break,
+                },
+                SolverOutcome::RunAgain => (),
+                SolverOutcome::RunOtherPart => {
+                    part = part.other();
+                    parts.retain(|&p| p != part);
+                }
+            }
+        }
+
+        // Return to the list of days for the same year
+        let view = self.build_day_view(year);
+        c.borrow_mut().add_layer(view);
+
+        if let Some((title, message)) = error {
+            c.borrow_mut().add_layer(build_error_view(title, message));
+        }
     }
 }
 
 impl Controller for Hub {
     fn show(&mut self, c: Rc<RefCell<Cursive>>) {
-        let view = build_hub_view(&self.registry);
+        let view = build_year_view(&self.registry);
         c.borrow_mut().add_layer(view);
+
+        if let Some(message) = self.startup_error.take() {
+            let view = build_error_view("Could not read past results", message);
+            c.borrow_mut().add_layer(view);
+        }
     }
 
     fn process_events(&mut self, c: Rc<RefCell<Cursive>>) -> bool {
         let events = take_events(&mut c.borrow_mut());
         for event in events {
             match event {
-                HubEvent::DaySelected(day) => {
-                    if !self.registry.has(day) {
-                        return true;
-                    }
+                HubEvent::YearSelected(year) => {
+                    let view = self.build_day_view(year);
+                    c.borrow_mut().add_layer(view);
+                }
+                HubEvent::DaySelected(year, day) => {
+                    let puzzle = match self.registry.get(year, day) {
+                        Some(puzzle) => puzzle.as_ref(),
+                        None => {
+                            let message = format!(
+                                "Day {} of {} has not been implemented yet, so there is nothing to run.",
+                                day, year
+                            );
+                            let view = build_error_view("Not implemented", message);
+                            c.borrow_mut().add_layer(view);
+                            return true;
+                        }
+                    };
 
-                    let view = build_part_view(day);
+                    let view = build_part_view(year, day, puzzle, &self.results);
+                    c.borrow_mut().add_layer(view);
+                }
+                HubEvent::PuzzleSelected(year, day, part) => {
+                    self.run_parts(Rc::clone(&c), year, day, &[part]);
+                }
+                HubEvent::BothPartsSelected(year, day) => {
+                    self.run_parts(
+                        Rc::clone(&c),
+                        year,
+                        day,
+                        &[PuzzlePart::One, PuzzlePart::Two],
+                    );
+                }
+                HubEvent::HistorySelected(year, day) => {
+                    let view = build_history_view(year, day, &self.results);
                     c.borrow_mut().add_layer(view);
                 }
-                HubEvent::PuzzleSelected(day, part) => {
-                    let puzzle = self.registry.get(day).as_ref().unwrap();
-                    if !puzzle.is_implemented(part) {
-                        return true;
-                    }
-
-                    c.borrow_mut().pop_layer();
-                    c.borrow_mut().pop_layer();
-                    puzzle.run(part, Rc::clone(&c));
-                    self.show(Rc::clone(&c));
+                HubEvent::FilterToggled(year) => {
+                    self.hide_unimplemented = !self.hide_unimplemented;
+                    let view = self.build_day_view(year);
+                    let mut siv = c.borrow_mut();
+                    siv.pop_layer();
+                    siv.add_layer(view);
                 }
             }
         }
@@ -60,39 +221,320 @@ impl Controller for Hub {
     }
 }
 
-fn build_hub_view(registry: &PuzzleRegistry) -> Dialog {
+fn build_year_view(registry: &PuzzleRegistry) -> Dialog {
     let select_view = registry
-        .get_puzzles()
+        .get_years()
+        .into_iter()
+        .rev()
+        .fold(SelectView::new(), |view, year| {
+            view.item(year.to_string(), year)
+        })
+        .on_submit(|c, year| {
+            emit(c, HubEvent::YearSelected(*year));
+        });
+
+    Dialog::new()
+        .title("Select Year")
+        .content(select_view)
+        .button("Quit", |c| c.quit())
+}
+
+fn build_day_view(
+    year: usize,
+    puzzles: &PuzzleSlots,
+    results: &ResultLog,
+    hide_unimplemented: bool,
+) -> Dialog {
+    let select_view = puzzles
         .iter()
         .enumerate()
-        .fold(SelectView::new(), |view, (day, puzzle)| {
+        .filter(|(_, puzzle)| !hide_unimplemented || is_implemented(puzzle.as_deref()))
+        .fold(SelectView::new(), |view, (i, puzzle)| {
+            let day = i + 1;
             let title = puzzle.as_ref().map(|puzzle| puzzle.get_title());
-            let label = format_puzzle(day, title);
+            let note = implementation_note(puzzle.as_deref());
+            let label = format_puzzle(day, title, results.stars(year, day), note);
             view.item(label, day)
         })
-        .on_submit(|c, day| {
-            emit(c, HubEvent::DaySelected(*day));
+        .on_submit(move |c, day| {
+            emit(c, HubEvent::DaySelected(year, *day));
         });
 
+    // Typing a day number selects that day. Any other key drops the digits
+    // typed so far.
+    let num_days = puzzles.len();
+    let typed = RefCell::new(DayJump::default());
+    let select_view = OnEventView::new(select_view).on_pre_event_inner(
+        EventTrigger::any(),
+        move |view, event| {
+            let digit = match event {
+                Event::Char(ch) if ch.is_ascii_digit() => ch.to_digit(10)? as usize,
+                Event::Refresh | Event::WindowResize => return None,
+                _ => {
+                    typed.borrow_mut().reset();
+                    return None;
+                }
+            };
+            let day = typed.borrow_mut().push(digit, num_days, Instant::now())?;
+            let index = view.iter().position(|(_, &d)| d == day)?;
+            Some(EventResult::Consumed(Some(view.set_selection(index))))
+        },
+    );
+
+    let filter_label = if hide_unimplemented {
+        "Show all"
+    } else {
+        "Hide unimplemented"
+    };
+
     Dialog::new()
-        .title("Select Puzzle")
+        .title(format!("Select Puzzle ({})", year))
         .content(select_view)
-        .button("Quit", |c| c.quit())
+        .button(filter_label, move |c| {
+            emit(c, HubEvent::FilterToggled(year))
+        })
+        .dismiss_button("Back")
 }
 
-fn format_puzzle(day: usize, title: Option<String>) -> String {
+/// Whether any part of a puzzle can be run
+fn is_implemented(puzzle: Option<&dyn Puzzle>) -> bool {
+    [PuzzlePart::One, PuzzlePart::Two]
+        .iter()
+        .any(|&part| is_part_implemented(puzzle, part))
+}
+
+fn is_part_implemented(puzzle: Option<&dyn Puzzle>, part: PuzzlePart) -> bool {
+    puzzle.is_some_and(|puzzle| puzzle.is_implemented(part))
+}
+
+const NOT_IMPLEMENTED: &str = "not implemented";
+
+/// Describe which parts of a puzzle are missing, if any
+fn implementation_note(puzzle: Option<&dyn Puzzle>) -> Option<&'static str> {
+    let implemented = |part| is_part_implemented(puzzle, part);
+    match (implemented(PuzzlePart::One), implemented(PuzzlePart::Two)) {
+        (true, true) => None,
+        (true, false) => Some("part 1 only"),
+        (false, true) => Some("part 2 only"),
+        (false, false) => Some(NOT_IMPLEMENTED),
+    }
+}
+
+/// How long a typed digit waits for the next one before a new day is started
+const JUMP_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Turns digits typed into the list of days into the day to select, so that
+/// typing "1" and then "2" selects day 1 and then day 12. A digit typed after
+/// a pause starts a new day.
+#[derive(Debug, Default)]
+struct DayJump {
+    typed: usize,
+    last_typed: Option<Instant>,
+}
+
+impl DayJump {
+    fn push(&mut self, digit: usize, num_days: usize, now: Instant) -> Option<usize> {
+        let is_day = |day| (1..=num_days).contains(&day);
+
+        if self
+            .last_typed
+            .is_some_and(|last| now.duration_since(last) > JUMP_TIMEOUT)
+        {
+            self.typed = 0;
+        }
+        self.last_typed = Some(now);
+
+        let day = self.typed * 10 + digit;
+        self.typed = if is_day(day) {
+            day
+        } else if is_day(digit) {
+            digit
+        } else {
+            0
+        };
+
+        Some(self.typed).filter(|&day| day > 0)
+    }
+
+    /// Forget the digits typed so far
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Label a puzzle with a star for each part that has been solved, noting
+/// which parts are not implemented
+fn format_puzzle(day: usize, title: Option<String>, stars: usize, note: Option<&str>) -> String {
     let title = title.unwrap_or("--".to_string());
-    format!("Day {:02}: {}", day + 1, title)
+    let label = format!("Day {:02}: {:<2} {}", day, "*".repeat(stars), title);
+    match note {
+        Some(note) => format!("{} ({})", label, note),
+        None => label,
+    }
+}
+
+/// Label a puzzle part with a check mark and its latest answer once solved
+fn format_part(part: PuzzlePart, answer: Option<&str>, is_implemented: bool) -> String {
+    if !is_implemented {
+        return format!("Part {} ({}) - {}", part, part, NOT_IMPLEMENTED);
+    }
+
+    match answer {
+        Some(answer) => format!("Part {} ({}) \u{2713} {}", part, part, answer),
+        None => format!("Part {} ({})", part, part),
+    }
 }
 
-fn build_part_view(day: usize) -> Dialog {
-    let select_view = SelectView::new()
-        .item("Part 1", PuzzlePart::One)
-        .item("Part 2", PuzzlePart::Two)
-        .on_submit(move |c, part| emit(c, HubEvent::PuzzleSelected(day, *part)));
+fn build_part_view(year: usize, day: usize, puzzle: &dyn Puzzle, results: &ResultLog) -> Dialog {
+    let select_view = [PuzzlePart::One, PuzzlePart::Two]
+        .iter()
+        .fold(SelectView::new(), |view, &part| {
+            let answer = results.latest_answer(year, day, part);
+            let label = format_part(part, answer, puzzle.is_implemented(part));
+            view.item(label, Some(part))
+        })
+        .item("Both parts (b)", None)
+        .on_submit(move |c, part| match *part {
+            Some(part) => emit(c, HubEvent::PuzzleSelected(year, day, part)),
+            None => emit(c, HubEvent::BothPartsSelected(year, day)),
+        });
+
+    // Pick a part directly without moving the selection
+    let select_view = OnEventView::new(select_view)
+        .on_event('1', move |c| {
+            emit(c, HubEvent::PuzzleSelected(year, day, PuzzlePart::One))
+        })
+        .on_event('2', move |c| {
+            emit(c, HubEvent::PuzzleSelected(year, day, PuzzlePart::Two))
+        })
+        .on_event('b', move |c| {
+            emit(c, HubEvent::BothPartsSelected(year, day))
+        });
 
     Dialog::new()
         .title("Select Part")
         .content(select_view)
+        .button("History", move |c| {
+            emit(c, HubEvent::HistorySelected(year, day))
+        })
+        .dismiss_button("Back")
+}
+
+fn build_history_view(year: usize, day: usize, results: &ResultLog) -> Dialog {
+    let history = results.history(year, day);
+    let text = if history.is_empty() {
+        "No completed runs yet".to_string()
+    } else {
+        history
+            .iter()
+            .rev()
+            .map(|past| {
+                let result = past.result;
+                format!(
+                    "{}  Part {}  {:>10.2?}  input {}  {}{}",
+                    format_timestamp(result.timestamp),
+                    result.part,
+                    result.duration,
+                    &result.input_hash[..8.min(result.input_hash.len())],
+                    result.answer,
+                    if past.changed { "  (changed)" } else { "" }
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    Dialog::new()
+        .title(format!("History ({} Day {})", year, day))
+        .content(TextView::new(text).scrollable())
         .dismiss_button("Back")
 }
+
+fn build_error_view(title: &str, message: String) -> Dialog {
+    Dialog::text(message).title(title).dismiss_button("Ok")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use crate::core::puzzle::{PuzzlePart, PuzzleRegistry};
+
+    use super::{
+        format_part, format_puzzle, implementation_note, is_implemented, DayJump, JUMP_TIMEOUT,
+        NOT_IMPLEMENTED,
+    };
+
+    #[test]
+    fn it_labels_solved_puzzles() {
+        let title = || Some("Report Repair".to_string());
+        assert_eq!(
+            format_puzzle(1, title(), 0, None),
+            "Day 01:    Report Repair"
+        );
+        assert_eq!(
+            format_puzzle(1, title(), 1, None),
+            "Day 01: *  Report Repair"
+        );
+        assert_eq!(format_puzzle(12, None, 2, None), "Day 12: ** --");
+
+        assert_eq!(format_part(PuzzlePart::One, None, true), "Part 1 (1)");
+        assert_eq!(
+            format_part(PuzzlePart::Two, Some("241861950"), true),
+            "Part 2 (2) \u{2713} 241861950"
+        );
+    }
+
+    #[test]
+    fn it_labels_unimplemented_puzzles() {
+        assert_eq!(
+            format_puzzle(12, None, 0, Some(NOT_IMPLEMENTED)),
+            "Day 12:    -- (not implemented)"
+        );
+        assert_eq!(
+            format_part(PuzzlePart::Two, Some("42"), false),
+            "Part 2 (2) - not implemented"
+        );
+
+        let registry = PuzzleRegistry::with_puzzles(25, crate::puzzles()).unwrap();
+        let note = |day| implementation_note(registry.get(2020, day).as_deref());
+        assert_eq!(note(1), None);
+        assert_eq!(note(25), Some(NOT_IMPLEMENTED));
+
+        let implemented = |day| is_implemented(registry.get(2020, day).as_deref());
+        assert!(implemented(11));
+        assert!(!implemented(25));
+    }
+
+    #[test]
+    fn it_jumps_to_typed_days() {
+        let mut jump = DayJump::default();
+        let now = Instant::now();
+        let mut typed = |digits: &[usize]| -> Vec<_> {
+            digits
+                .iter()
+                .map(|&digit| jump.push(digit, 25, now))
+                .collect()
+        };
+
+        assert_eq!(typed(&[1, 2]), vec![Some(1), Some(12)]);
+        assert_eq!(typed(&[3]), vec![Some(3)]);
+        assert_eq!(typed(&[0, 7]), vec![None, Some(7)]);
+        assert_eq!(typed(&[2, 5, 0]), vec![Some(2), Some(25), None]);
+        assert_eq!(typed(&[9, 2]), vec![Some(9), Some(2)]);
+    }
+
+    #[test]
+    fn it_forgets_typed_digits_after_a_pause_or_reset() {
+        let mut jump = DayJump::default();
+        let start = Instant::now();
+
+        assert_eq!(jump.push(1, 25, start), Some(1));
+        let later = start + JUMP_TIMEOUT * 2;
+        assert_eq!(jump.push(2, 25, later), Some(2));
+        assert_eq!(jump.push(1, 25, later), Some(21));
+
+        jump.reset();
+        assert_eq!(jump.push(2, 25, later), Some(2));
+    }
+}
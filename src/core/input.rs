@@ -0,0 +1,139 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use thiserror::Error;
+
+use super::puzzle::Puzzle;
+
+/// Environment variable that overrides the default input directory
+pub const INPUT_DIR_VAR: &str = "ADVENT_INPUT_DIR";
+
+pub const DEFAULT_INPUT_DIR: &str = "input";
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("Could not find puzzle input at {}", .0.display())]
+    NotFound(PathBuf),
+
+    #[error("Could not read puzzle input from {}", .0.display())]
+    Io(PathBuf, #[source] io::Error),
+
+    #[error("Could not read puzzle input from stdin")]
+    Stdin(#[source] io::Error),
+}
+
+pub type Result<T> = std::result::Result<T, InputError>;
+
+/// Get the directory named by the input directory environment variable,
+/// falling back to the default input directory
+pub fn root_from_env() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// Resolves where a puzzle's input is read from
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputProvider {
+    /// Read each puzzle's input path relative to a root directory
+    Root(PathBuf),
+
+    /// Read the input from the given file, regardless of the puzzle
+    File(PathBuf),
+
+    /// Read the input from stdin, regardless of the puzzle
+    Stdin,
+}
+
+impl InputProvider {
+    /// Use the directory named by the input directory environment variable,
+    /// falling back to the default input directory
+    pub fn from_env() -> Self {
+        InputProvider::Root(root_from_env())
+    }
+
+    /// Get the path that the puzzle's input would be read from, if any
+    pub fn locate(&self, puzzle: &dyn Puzzle) -> Option<PathBuf> {
+        match self {
+            InputProvider::Root(root) => Some(root.join(puzzle.get_input_path())),
+            InputProvider::File(path) => Some(path.clone()),
+            InputProvider::Stdin => None,
+        }
+    }
+
+    pub fn load(&self, puzzle: &dyn Puzzle) -> Result<String> {
+        match self.locate(puzzle) {
+            Some(path) => read_file(path),
+            None => read_stdin(),
+        }
+    }
+}
+
+fn read_file(path: PathBuf) -> Result<String> {
+    fs::read_to_string(&path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path),
+        _ => InputError::Io(path, err),
+    })
+}
+
+fn read_stdin() -> Result<String> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(InputError::Stdin)?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{core::util::test_dir, day01::Day01};
+
+    use super::{InputError, InputProvider};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = test_dir(&format!("input-{}", name));
+        fs::create_dir_all(dir.join("day01")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn it_loads_input_relative_to_the_root() {
+        let root = temp_dir("root");
+        fs::write(root.join("day01/expense_report.txt"), "1721\n979\n").unwrap();
+
+        let provider = InputProvider::Root(root.clone());
+        assert_eq!(
+            provider.locate(&Day01::new()),
+            Some(root.join("day01/expense_report.txt"))
+        );
+        assert_eq!(provider.load(&Day01::new()).unwrap(), "1721\n979\n");
+    }
+
+    #[test]
+    fn it_loads_an_explicit_file() {
+        let root = temp_dir("file");
+        let path = root.join("custom.txt");
+        fs::write(&path, "1\n2\n").unwrap();
+
+        let provider = InputProvider::File(path);
+        assert_eq!(provider.load(&Day01::new()).unwrap(), "1\n2\n");
+    }
+
+    #[test]
+    fn it_reports_missing_input() {
+        let root = temp_dir("missing");
+        let provider = InputProvider::Root(root.clone());
+
+        match provider.load(&Day01::new()) {
+            Err(InputError::NotFound(path)) => {
+                assert_eq!(path, root.join("day01/expense_report.txt"))
+            }
+            other => panic!("Expected NotFound, got {:?}", other),
+        }
+    }
+}
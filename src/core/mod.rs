@@ -1,16 +1,31 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod clipboard;
 pub mod controller;
 pub mod fs;
+pub mod geom;
 pub mod grid;
+pub mod history;
 pub mod hub;
+pub mod input;
+pub mod offscreen;
+pub mod playback;
 pub mod puzzle;
+pub mod recording;
+pub mod remote;
+pub mod results;
 pub mod solver;
+pub mod sparse_grid;
+pub mod submissions;
 pub mod util;
 pub mod wrap_grid;
 
 pub use controller::*;
 pub use fs::*;
 pub use grid::Grid;
-pub use puzzle::{Puzzle, PuzzlePart, PuzzleRegistry};
+pub use puzzle::{BoxedSolver, Puzzle, PuzzlePart, PuzzleRegistry};
 pub use solver::{Solver, SolverController, SolverEvent};
+pub use sparse_grid::SparseGrid;
 pub use util::last_n;
 pub use wrap_grid::WrapGrid;
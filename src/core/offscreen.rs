@@ -0,0 +1,346 @@
+use std::cell::{Cell, RefCell};
+
+use cursive::{
+    backend::Backend,
+    event::Event,
+    theme::{BaseColor, Color, ColorPair, ColorStyle, Effect, Theme},
+    Printer, Vec2,
+};
+use enumset::EnumSet;
+
+use super::solver::Solver;
+
+/// A single character on an off-screen frame along with its style
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Glyph {
+    pub ch: char,
+    pub colors: ColorPair,
+    pub effects: EnumSet<Effect>,
+}
+
+/// A cursive backend that draws into memory instead of a terminal
+pub struct OffscreenBackend {
+    size: Vec2,
+    glyphs: RefCell<Vec<Glyph>>,
+    colors: Cell<ColorPair>,
+    effects: Cell<EnumSet<Effect>>,
+}
+
+impl OffscreenBackend {
+    /// Create a backend of the given size, filled with blanks in the given
+    /// colors
+    pub fn new(size: Vec2, colors: ColorPair) -> Self {
+        let blank = Glyph {
+            ch: ' ',
+            colors,
+            effects: EnumSet::new(),
+        };
+
+        Self {
+            size,
+            glyphs: RefCell::new(vec![blank; size.x * size.y]),
+            colors: Cell::new(colors),
+            effects: Cell::new(EnumSet::new()),
+        }
+    }
+
+    /// Take a copy of everything drawn so far
+    pub fn frame(&self) -> Frame {
+        Frame {
+            size: self.size,
+            glyphs: self.glyphs.borrow().clone(),
+        }
+    }
+}
+
+impl Backend for OffscreenBackend {
+    fn poll_event(&mut self) -> Option<Event> {
+        None
+    }
+
+    fn finish(&mut self) {}
+
+    fn refresh(&mut self) {}
+
+    fn has_colors(&self) -> bool {
+        true
+    }
+
+    fn screen_size(&self) -> Vec2 {
+        self.size
+    }
+
+    fn print_at(&self, pos: Vec2, text: &str) {
+        if pos.y >= self.size.y {
+            return;
+        }
+
+        let mut glyphs = self.glyphs.borrow_mut();
+        let row = pos.y * self.size.x;
+        for (x, ch) in (pos.x..self.size.x).zip(text.chars()) {
+            glyphs[row + x] = Glyph {
+                ch,
+                colors: self.colors.get(),
+                effects: self.effects.get(),
+            };
+        }
+    }
+
+    fn clear(&self, color: Color) {
+        let blank = Glyph {
+            ch: ' ',
+            colors: ColorPair {
+                front: color,
+                back: color,
+            },
+            effects: EnumSet::new(),
+        };
+        self.glyphs.borrow_mut().iter_mut().for_each(|g| *g = blank);
+    }
+
+    fn set_color(&self, colors: ColorPair) -> ColorPair {
+        self.colors.replace(colors)
+    }
+
+    fn set_effect(&self, effect: Effect) {
+        self.effects.set(self.effects.get() | effect);
+    }
+
+    fn unset_effect(&self, effect: Effect) {
+        self.effects.set(self.effects.get() - effect);
+    }
+
+    fn name(&self) -> &str {
+        "offscreen"
+    }
+}
+
+/// Everything drawn on an off-screen backend, row by row
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Frame {
+    size: Vec2,
+    glyphs: Vec<Glyph>,
+}
+
+impl Frame {
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    pub fn glyph(&self, x: usize, y: usize) -> Option<&Glyph> {
+        if x < self.size.x && y < self.size.y {
+            self.glyphs.get(y * self.size.x + x)
+        } else {
+            None
+        }
+    }
+
+    /// Get the text of each row, without trailing whitespace
+    pub fn lines(&self) -> Vec<String> {
+        self.rows()
+            .map(|row| {
+                let line: String = row.iter().map(|g| g.ch).collect();
+                line.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    /// Get the text of the frame, without trailing whitespace or blank rows
+    pub fn text(&self) -> String {
+        let mut lines = self.lines();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        lines.join("\n")
+    }
+
+    /// Map each glyph to a character, row by row, without trailing
+    /// whitespace. This is useful for asserting on where styles were used.
+    pub fn map_lines<F>(&self, f: F) -> Vec<String>
+    where
+        F: Fn(&Glyph) -> char,
+    {
+        self.rows()
+            .map(|row| {
+                let line: String = row.iter().map(&f).collect();
+                line.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    /// Mark each glyph with the default theme's color style that it was
+    /// printed in: ' ' for primary, 's' for secondary, 't' for tertiary, and
+    /// '?' for anything else. Reversed glyphs are marked with 'r'.
+    pub fn style_lines(&self) -> Vec<String> {
+        let palette = Theme::default().palette;
+        let primary = ColorStyle::primary().resolve(&palette);
+        let secondary = ColorStyle::secondary().resolve(&palette);
+        let tertiary = ColorStyle::tertiary().resolve(&palette);
+
+        self.map_lines(|glyph| {
+            if glyph.effects.contains(Effect::Reverse) {
+                'r'
+            } else if glyph.colors == primary {
+                ' '
+            } else if glyph.colors == secondary {
+                's'
+            } else if glyph.colors == tertiary {
+                't'
+            } else {
+                '?'
+            }
+        })
+    }
+
+    /// Format the frame with ANSI escape codes for its colors and effects,
+    /// with rows separated by CRLF
+    pub fn to_ansi(&self) -> String {
+        let rows: Vec<_> = self
+            .rows()
+            .map(|row| {
+                let mut line = String::new();
+                let mut style = None;
+                for glyph in row {
+                    let glyph_style = (glyph.colors, glyph.effects);
+                    if style != Some(glyph_style) {
+                        line += &sgr(glyph.colors, glyph.effects);
+                        style = Some(glyph_style);
+                    }
+                    line.push(glyph.ch);
+                }
+                line + "\x1b[0m"
+            })
+            .collect();
+
+        rows.join("\r\n")
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Glyph]> {
+        self.glyphs.chunks(self.size.x.max(1))
+    }
+}
+
+/// Render a drawing function onto an off-screen frame of the given size, using
+/// the default theme
+pub fn render<F>(size: Vec2, draw: F) -> Frame
+where
+    F: FnOnce(&Printer),
+{
+    let theme = Theme::default();
+    let colors = ColorStyle::primary().resolve(&theme.palette);
+    let backend = OffscreenBackend::new(size, colors);
+
+    let printer = Printer::new(size, &theme, &backend);
+    draw(&printer);
+
+    backend.frame()
+}
+
+/// Render a solver's current state onto an off-screen frame of the given size
+pub fn render_solver<S, T>(solver: &S, size: Vec2) -> Frame
+where
+    S: Solver<T> + ?Sized,
+{
+    render(size, |printer| solver.draw(printer))
+}
+
+/// The ANSI select graphic rendition sequence for a style
+fn sgr(colors: ColorPair, effects: EnumSet<Effect>) -> String {
+    let mut codes = vec!["0".to_string()];
+    for effect in effects {
+        let code = match effect {
+            Effect::Simple => continue,
+            Effect::Bold => "1",
+            Effect::Italic => "3",
+            Effect::Underline => "4",
+            Effect::Reverse => "7",
+            Effect::Strikethrough => "9",
+        };
+        codes.push(code.to_string());
+    }
+    codes.push(color_code(colors.front, 30));
+    codes.push(color_code(colors.back, 40));
+
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// The ANSI code for a color, where the base is 30 for foreground colors and
+/// 40 for background colors
+fn color_code(color: Color, base: u8) -> String {
+    match color {
+        Color::TerminalDefault => (base + 9).to_string(),
+        Color::Dark(color) => (base + base_color_index(color)).to_string(),
+        Color::Light(color) => (base + 60 + base_color_index(color)).to_string(),
+        Color::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b),
+        Color::RgbLowRes(r, g, b) => format!("{};5;{}", base + 8, 16 + 36 * r + 6 * g + b),
+    }
+}
+
+fn base_color_index(color: BaseColor) -> u8 {
+    match color {
+        BaseColor::Black => 0,
+        BaseColor::Red => 1,
+        BaseColor::Green => 2,
+        BaseColor::Yellow => 3,
+        BaseColor::Blue => 4,
+        BaseColor::Magenta => 5,
+        BaseColor::Cyan => 6,
+        BaseColor::White => 7,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cursive::{
+        theme::{BaseColor, Color, ColorPair, ColorStyle, Effect, Theme},
+        Vec2,
+    };
+
+    use super::render;
+
+    #[test]
+    fn it_renders_text_and_styles() {
+        let frame = render(Vec2::new(8, 3), |printer| {
+            printer.print((1, 0), "hello world");
+            printer.with_color(ColorStyle::secondary(), |printer| {
+                printer.print((0, 1), "#");
+            });
+            printer.with_effect(Effect::Reverse, |printer| {
+                printer.print((2, 1), "x");
+            });
+        });
+
+        assert_eq!(frame.lines(), vec![" hello w", "# x", ""]);
+        assert_eq!(frame.text(), " hello w\n# x");
+
+        let palette = Theme::default().palette;
+        let primary = ColorStyle::primary().resolve(&palette);
+        assert_eq!(frame.glyph(0, 0).unwrap().colors, primary);
+        assert_eq!(
+            frame.glyph(0, 1).unwrap().colors,
+            ColorStyle::secondary().resolve(&palette)
+        );
+        assert!(frame.glyph(2, 1).unwrap().effects.contains(Effect::Reverse));
+        assert!(frame.glyph(8, 0).is_none());
+        assert_eq!(frame.style_lines(), vec!["", "s r", ""]);
+    }
+
+    #[test]
+    fn it_formats_ansi() {
+        let frame = render(Vec2::new(2, 2), |printer| {
+            printer.print((0, 0), "ab");
+        });
+        let colors = ColorPair {
+            front: Color::Dark(BaseColor::Black),
+            back: Color::Dark(BaseColor::White),
+        };
+        assert_eq!(
+            ColorStyle::primary().resolve(&Theme::default().palette),
+            colors
+        );
+        assert_eq!(
+            frame.to_ansi(),
+            "\x1b[0;30;47mab\x1b[0m\r\n\x1b[0;30;47m  \x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+
+/// The fastest playback speed, in steps per frame
+pub const MAX_SPEED: usize = 1024;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PlaybackMode {
+    Playing,
+    Paused,
+    /// Step as fast as possible until the solver is done
+    Finishing,
+}
+
+/// How many steps a solver should take on the next frame
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Frame {
+    Steps(usize),
+    Finish,
+}
+
+/// Tracks the playback mode and speed of a solver's visualization
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Playback {
+    mode: PlaybackMode,
+    speed: usize,
+    pending_steps: usize,
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self {
+            mode: PlaybackMode::Playing,
+            speed: 1,
+            pending_steps: 0,
+        }
+    }
+}
+
+impl Playback {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn mode(&self) -> PlaybackMode {
+        self.mode
+    }
+
+    /// The number of steps taken per frame while playing
+    pub fn speed(&self) -> usize {
+        self.speed
+    }
+
+    pub fn pause(&mut self) {
+        self.mode = PlaybackMode::Paused;
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.mode = match self.mode {
+            PlaybackMode::Paused => PlaybackMode::Playing,
+            _ => PlaybackMode::Paused,
+        };
+    }
+
+    /// Pause and take a single step on the next frame
+    pub fn step_once(&mut self) {
+        self.step_by(1);
+    }
+
+    /// Pause and take the given number of steps on the next frame
+    pub fn step_by(&mut self, steps: usize) {
+        self.mode = PlaybackMode::Paused;
+        self.pending_steps += steps;
+    }
+
+    pub fn faster(&mut self) {
+        self.speed = (self.speed * 2).min(MAX_SPEED);
+    }
+
+    pub fn slower(&mut self) {
+        self.speed = (self.speed / 2).max(1);
+    }
+
+    pub fn finish(&mut self) {
+        self.mode = PlaybackMode::Finishing;
+    }
+
+    /// Get the steps to take on the next frame
+    pub fn next_frame(&mut self) -> Frame {
+        match self.mode {
+            PlaybackMode::Playing => Frame::Steps(self.speed),
+            PlaybackMode::Paused => Frame::Steps(std::mem::take(&mut self.pending_steps)),
+            PlaybackMode::Finishing => Frame::Finish,
+        }
+    }
+}
+
+impl fmt::Display for Playback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            PlaybackMode::Playing => write!(f, "Playing x{}", self.speed),
+            PlaybackMode::Paused => write!(f, "Paused"),
+            PlaybackMode::Finishing => write!(f, "Finishing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frame, Playback, PlaybackMode, MAX_SPEED};
+
+    #[test]
+    fn it_pauses_and_steps() {
+        let mut playback = Playback::new();
+        assert_eq!(playback.next_frame(), Frame::Steps(1));
+
+        playback.toggle_pause();
+        assert_eq!(playback.mode(), PlaybackMode::Paused);
+        assert_eq!(playback.next_frame(), Frame::Steps(0));
+
+        playback.step_once();
+        playback.step_once();
+        assert_eq!(playback.next_frame(), Frame::Steps(2));
+        assert_eq!(playback.next_frame(), Frame::Steps(0));
+
+        playback.toggle_pause();
+        assert_eq!(playback.next_frame(), Frame::Steps(1));
+        assert_eq!(playback.to_string(), "Playing x1");
+    }
+
+    #[test]
+    fn it_changes_speed() {
+        let mut playback = Playback::new();
+        playback.slower();
+        assert_eq!(playback.speed(), 1);
+
+        playback.faster();
+        playback.faster();
+        assert_eq!(playback.next_frame(), Frame::Steps(4));
+
+        (0..20).for_each(|_| playback.faster());
+        assert_eq!(playback.speed(), MAX_SPEED);
+
+        playback.toggle_pause();
+        playback.toggle_pause();
+        assert_eq!(playback.speed(), MAX_SPEED);
+    }
+
+    #[test]
+    fn it_runs_to_completion() {
+        let mut playback = Playback::new();
+        playback.finish();
+        assert_eq!(playback.next_frame(), Frame::Finish);
+        assert_eq!(playback.to_string(), "Finishing");
+
+        playback.step_once();
+        assert_eq!(playback.next_frame(), Frame::Steps(1));
+    }
+}
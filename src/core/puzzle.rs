@@ -1,17 +1,139 @@
-use cursive::Cursive;
-use std::{cell::RefCell, fmt, rc::Rc};
+use std::{collections::BTreeMap, fmt, path::Path, str::FromStr};
 use thiserror::Error;
 
+use super::{fs::ParseIoError, grid::GridError, solver::Solver};
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum PuzzlePart {
     One,
     Two,
 }
 
+impl fmt::Display for PuzzlePart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PuzzlePart::One => write!(f, "1"),
+            PuzzlePart::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Puzzle part must be 1 or 2, got '{0}'")]
+pub struct ParsePuzzlePartError(String);
+
+impl PuzzlePart {
+    pub fn other(&self) -> Self {
+        match self {
+            PuzzlePart::One => PuzzlePart::Two,
+            PuzzlePart::Two => PuzzlePart::One,
+        }
+    }
+}
+
+impl FromStr for PuzzlePart {
+    type Err = ParsePuzzlePartError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(PuzzlePart::One),
+            "2" => Ok(PuzzlePart::Two),
+            _ => Err(ParsePuzzlePartError(s.to_string())),
+        }
+    }
+}
+
+pub type BoxedSolver = Box<dyn Solver<String>>;
+
+/// An error building a solver for a puzzle
+#[derive(Debug, Error)]
+pub enum PuzzleError {
+    /// The puzzle input could not be parsed. Lines are numbered from 1.
+    #[error("{message}")]
+    Parse {
+        line: Option<usize>,
+        message: String,
+    },
+
+    #[error("Part {0} is not implemented")]
+    NotImplemented(PuzzlePart),
+
+    /// The input was parsed but the puzzle cannot be solved from it
+    #[error("{0}")]
+    Unsolvable(String),
+}
+
+impl PuzzleError {
+    /// Describe the error along with where the input was read from, which is
+    /// stdin if there is no path
+    pub fn describe(&self, path: Option<&Path>) -> String {
+        let path = path.map_or("<stdin>".to_string(), |path| path.display().to_string());
+        match self {
+            PuzzleError::Parse {
+                line: Some(line), ..
+            } => format!("{}:{}: {}", path, line, self),
+            PuzzleError::Parse { line: None, .. } => format!("{}: {}", path, self),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl From<ParseIoError> for PuzzleError {
+    fn from(err: ParseIoError) -> Self {
+        match err {
+            ParseIoError::Parse(line, message) => PuzzleError::Parse {
+                line: Some(line),
+                message,
+            },
+            ParseIoError::Io(err) => PuzzleError::Parse {
+                line: None,
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
+impl From<GridError> for PuzzleError {
+    fn from(err: GridError) -> Self {
+        let line = match err {
+            GridError::EmptyGrid | GridError::OutOfBounds(_, _) => None,
+            GridError::RowsNotSameLength(row) => Some(row + 1),
+        };
+        PuzzleError::Parse {
+            line,
+            message: err.to_string(),
+        }
+    }
+}
+
+pub type SolverResult = std::result::Result<BoxedSolver, PuzzleError>;
+
 pub trait Puzzle: fmt::Debug {
     fn get_title(&self) -> String;
+
+    /// The year of the event that this puzzle belongs to
+    fn get_year(&self) -> usize;
+
+    /// The day of the event that this puzzle belongs to, numbered from 1
+    fn get_day(&self) -> usize;
+
+    /// Path to the puzzle input, relative to the input directory
+    fn get_input_path(&self) -> String;
+
     fn is_implemented(&self, part: PuzzlePart) -> bool;
-    fn run(&self, part: PuzzlePart, c: Rc<RefCell<Cursive>>);
+
+    /// Build a solver for the given part from the contents of the puzzle
+    /// input, failing if the input cannot be parsed
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult;
+
+    /// Solve the given part without a UI, returning the formatted solution
+    fn solve(
+        &self,
+        part: PuzzlePart,
+        input: &str,
+    ) -> std::result::Result<Option<String>, PuzzleError> {
+        Ok(self.solver(part, input)?.solve())
+    }
 }
 
 #[derive(Debug, Error)]
@@ -19,24 +141,26 @@ pub enum PuzzleRegistryError {
     #[error("Puzzle day must be between 1 and {1}, got {0}")]
     DayOutOfBounds(usize, usize),
 
-    #[error("Another puzzle is already registered for day {0}")]
-    AlreadyRegistered(usize),
+    #[error("Another puzzle is already registered for {0} day {1}")]
+    AlreadyRegistered(usize, usize),
 }
 
 pub type Result<T> = std::result::Result<T, PuzzleRegistryError>;
 
+pub type PuzzleSlots = Vec<Option<Box<dyn Puzzle>>>;
+
 #[derive(Debug)]
 pub struct PuzzleRegistry {
     num_days: usize,
-    puzzles: Vec<Option<Box<dyn Puzzle>>>,
+    years: BTreeMap<usize, PuzzleSlots>,
 }
 
 impl PuzzleRegistry {
     pub fn new(num_days: usize) -> Self {
-        let mut puzzles = Vec::with_capacity(num_days);
-        (0..num_days).for_each(|_| puzzles.push(None));
-
-        Self { num_days, puzzles }
+        Self {
+            num_days,
+            years: BTreeMap::new(),
+        }
     }
 
     pub fn with_puzzles(num_days: usize, puzzles: Vec<Box<dyn Puzzle>>) -> Result<Self> {
@@ -45,41 +169,190 @@ impl PuzzleRegistry {
         Ok(registry)
     }
 
+    /// Register each puzzle under the year and day that it declares
     pub fn register(&mut self, puzzles: Vec<Box<dyn Puzzle>>) -> Result<()> {
         puzzles
             .into_iter()
-            .enumerate()
-            .map(|(i, puzzle)| self.insert(i, puzzle))
-            .collect()
+            .try_for_each(|puzzle| self.insert(puzzle))
     }
 
-    pub fn has(&self, day: usize) -> bool {
-        day < self.num_days && self.puzzles[day].is_some()
+    pub fn has(&self, year: usize, day: usize) -> bool {
+        self.get(year, day).is_some()
     }
 
-    pub fn insert(&mut self, day: usize, puzzle: Box<dyn Puzzle>) -> Result<()> {
-        if day >= self.num_days {
-            return Err(PuzzleRegistryError::DayOutOfBounds(day, self.num_days));
-        }
+    pub fn insert(&mut self, puzzle: Box<dyn Puzzle>) -> Result<()> {
+        let year = puzzle.get_year();
+        let day = puzzle.get_day();
+        let index = self
+            .index(day)
+            .ok_or(PuzzleRegistryError::DayOutOfBounds(day, self.num_days))?;
+
+        let num_days = self.num_days;
+        let slots = self.years.entry(year).or_insert_with(|| {
+            let mut slots = Vec::with_capacity(num_days);
+            (0..num_days).for_each(|_| slots.push(None));
+            slots
+        });
 
-        if let Some(_) = &self.puzzles[day] {
-            return Err(PuzzleRegistryError::AlreadyRegistered(day));
+        if slots[index].is_some() {
+            return Err(PuzzleRegistryError::AlreadyRegistered(year, day));
         }
 
-        self.puzzles[day] = Some(puzzle);
+        slots[index] = Some(puzzle);
 
         Ok(())
     }
 
-    pub fn get(&self, day: usize) -> &Option<Box<dyn Puzzle>> {
-        if day >= self.num_days {
-            return &None;
+    /// Get the puzzle registered for the given year and day. Days are numbered
+    /// from 1.
+    pub fn get(&self, year: usize, day: usize) -> &Option<Box<dyn Puzzle>> {
+        match (self.years.get(&year), self.index(day)) {
+            (Some(slots), Some(index)) => &slots[index],
+            _ => &None,
+        }
+    }
+
+    /// Get every year with at least one registered puzzle, in ascending order
+    pub fn get_years(&self) -> Vec<usize> {
+        self.years.keys().cloned().collect()
+    }
+
+    pub fn latest_year(&self) -> Option<usize> {
+        self.years.keys().next_back().cloned()
+    }
+
+    /// Get every puzzle slot for the given year, where the puzzle for day N is
+    /// at index N - 1
+    pub fn get_puzzles(&self, year: usize) -> Option<&PuzzleSlots> {
+        self.years.get(&year)
+    }
+
+    fn index(&self, day: usize) -> Option<usize> {
+        if day == 0 || day > self.num_days {
+            None
+        } else {
+            Some(day - 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::core::{fs::parse_str_lines, grid::Grid};
+
+    use super::{
+        Puzzle, PuzzleError, PuzzlePart, PuzzleRegistry, PuzzleRegistryError, SolverResult,
+    };
+
+    #[derive(Debug)]
+    struct TestPuzzle(usize, usize);
+
+    impl Puzzle for TestPuzzle {
+        fn get_title(&self) -> String {
+            format!("Test {} {}", self.0, self.1)
+        }
+
+        fn get_year(&self) -> usize {
+            self.0
         }
 
-        &self.puzzles[day]
+        fn get_day(&self) -> usize {
+            self.1
+        }
+
+        fn get_input_path(&self) -> String {
+            String::new()
+        }
+
+        fn is_implemented(&self, _part: PuzzlePart) -> bool {
+            false
+        }
+
+        fn solver(&self, part: PuzzlePart, _input: &str) -> SolverResult {
+            Err(PuzzleError::NotImplemented(part))
+        }
+    }
+
+    fn puzzles(entries: &[(usize, usize)]) -> Vec<Box<dyn Puzzle>> {
+        entries
+            .iter()
+            .map(|&(year, day)| Box::new(TestPuzzle(year, day)) as Box<dyn Puzzle>)
+            .collect()
+    }
+
+    #[test]
+    fn it_registers_puzzles_by_declared_year_and_day() {
+        let entries = [(2020, 3), (2019, 1), (2020, 25), (2020, 1)];
+        let registry = PuzzleRegistry::with_puzzles(25, puzzles(&entries)).unwrap();
+
+        assert!(registry.has(2020, 1));
+        assert!(!registry.has(2020, 2));
+        assert!(registry.has(2020, 3));
+        assert!(registry.has(2020, 25));
+        assert!(registry.has(2019, 1));
+        assert!(!registry.has(2019, 3));
+        assert!(!registry.has(2018, 1));
+        assert!(!registry.has(2020, 0));
+        assert!(!registry.has(2020, 26));
+        assert_eq!(
+            registry.get(2020, 3).as_ref().map(|p| p.get_title()),
+            Some("Test 2020 3".to_string())
+        );
+        assert_eq!(registry.get_years(), vec![2019, 2020]);
+        assert_eq!(registry.latest_year(), Some(2020));
+    }
+
+    #[test]
+    fn it_registers_every_puzzle_in_the_crate() {
+        let registry = PuzzleRegistry::with_puzzles(25, crate::puzzles()).unwrap();
+        assert!((1..=11).all(|day| registry.has(2020, day)));
+    }
+
+    #[test]
+    fn it_rejects_conflicting_days() {
+        let entries = [(2020, 2), (2019, 2), (2020, 5), (2020, 2)];
+        let result = PuzzleRegistry::with_puzzles(25, puzzles(&entries));
+        assert!(matches!(
+            result,
+            Err(PuzzleRegistryError::AlreadyRegistered(2020, 2))
+        ));
     }
 
-    pub fn get_puzzles(&self) -> &Vec<Option<Box<dyn Puzzle>>> {
-        &self.puzzles
+    #[test]
+    fn it_rejects_days_out_of_bounds() {
+        let result = PuzzleRegistry::with_puzzles(25, puzzles(&[(2020, 0)]));
+        assert!(matches!(
+            result,
+            Err(PuzzleRegistryError::DayOutOfBounds(0, 25))
+        ));
+
+        let result = PuzzleRegistry::with_puzzles(25, puzzles(&[(2020, 26)]));
+        assert!(matches!(
+            result,
+            Err(PuzzleRegistryError::DayOutOfBounds(26, 25))
+        ));
+    }
+
+    #[test]
+    fn it_describes_where_parsing_failed() {
+        let err = PuzzleError::from(parse_str_lines::<i64>("1\n2\nx").unwrap_err());
+        assert_eq!(
+            err.describe(Some(Path::new("input/day01/expense_report.txt"))),
+            "input/day01/expense_report.txt:3: invalid digit found in string"
+        );
+
+        let err = PuzzleError::from("..\n.\n..".parse::<Grid<char>>().unwrap_err());
+        assert_eq!(
+            err.describe(None),
+            "<stdin>:2: Could not parse grid with different length rows"
+        );
+
+        let err = PuzzleError::from("".parse::<Grid<char>>().unwrap_err());
+        assert_eq!(
+            err.describe(None),
+            "<stdin>: Could not parse grid with 0 width or height"
+        );
     }
 }
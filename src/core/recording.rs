@@ -0,0 +1,239 @@
+use std::{
+    io::{self, Write},
+    str::FromStr,
+};
+
+use cursive::Vec2;
+use thiserror::Error;
+
+use super::{
+    offscreen::{render_solver, Frame},
+    solver::Solver,
+    util::JsonString,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecordingFormat {
+    /// An asciinema v2 recording
+    Cast,
+    /// Plain text frames, each preceded by a header line
+    Text,
+}
+
+#[derive(Debug, Error)]
+#[error("Recording format must be cast or text, got '{0}'")]
+pub struct ParseRecordingFormatError(String);
+
+impl FromStr for RecordingFormat {
+    type Err = ParseRecordingFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cast" => Ok(RecordingFormat::Cast),
+            "text" | "txt" => Ok(RecordingFormat::Text),
+            _ => Err(ParseRecordingFormatError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RecordingOptions {
+    pub size: Vec2,
+    /// The number of solver steps between frames
+    pub every: usize,
+    pub fps: usize,
+    /// The most frames to record before the solver is stepped to completion
+    /// without recording. The final frame is always recorded.
+    pub max_frames: usize,
+}
+
+impl Default for RecordingOptions {
+    fn default() -> Self {
+        Self {
+            size: Vec2::new(80, 24),
+            every: 1,
+            fps: 30,
+            max_frames: 1000,
+        }
+    }
+}
+
+/// A frame of a recording, along with the number of steps taken before it
+#[derive(Debug, Clone)]
+pub struct RecordedFrame {
+    pub step: usize,
+    pub frame: Frame,
+}
+
+/// Step a solver to completion, drawing it off-screen before the first step,
+/// after every `every` steps, and after the last step
+pub fn record<S, T>(solver: &mut S, options: &RecordingOptions) -> Vec<RecordedFrame>
+where
+    S: Solver<T> + ?Sized,
+{
+    let every = options.every.max(1);
+    let capture = |solver: &S, step| RecordedFrame {
+        step,
+        frame: render_solver(solver, options.size),
+    };
+
+    let mut frames = vec![capture(solver, 0)];
+    let mut step = 0;
+    while !solver.is_done() {
+        solver.step();
+        step += 1;
+
+        let is_last = solver.is_done();
+        if is_last || (step % every == 0 && frames.len() < options.max_frames) {
+            frames.push(capture(solver, step));
+        }
+    }
+
+    frames
+}
+
+pub fn write_recording<W: Write>(
+    writer: &mut W,
+    frames: &[RecordedFrame],
+    options: &RecordingOptions,
+    format: RecordingFormat,
+    title: &str,
+) -> io::Result<()> {
+    match format {
+        RecordingFormat::Cast => write_cast(writer, frames, options, title),
+        RecordingFormat::Text => write_text(writer, frames),
+    }
+}
+
+/// Write the frames as an asciinema v2 recording, where each frame clears the
+/// screen and redraws it
+pub fn write_cast<W: Write>(
+    writer: &mut W,
+    frames: &[RecordedFrame],
+    options: &RecordingOptions,
+    title: &str,
+) -> io::Result<()> {
+    writeln!(
+        writer,
+        "{{\"version\": 2, \"width\": {}, \"height\": {}, \"title\": {}}}",
+        options.size.x,
+        options.size.y,
+        JsonString(title)
+    )?;
+
+    let fps = options.fps.max(1) as f64;
+    for (i, recorded) in frames.iter().enumerate() {
+        let data = format!("\x1b[H\x1b[2J{}", recorded.frame.to_ansi());
+        writeln!(
+            writer,
+            "[{:.6}, \"o\", {}]",
+            i as f64 / fps,
+            JsonString(&data)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Write the frames as plain text, each preceded by a header with its step
+pub fn write_text<W: Write>(writer: &mut W, frames: &[RecordedFrame]) -> io::Result<()> {
+    for recorded in frames {
+        writeln!(writer, "--- step {} ---", recorded.step)?;
+        writeln!(writer, "{}", recorded.frame.text())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use cursive::Vec2;
+
+    use crate::core::{geom, grid::Grid};
+    use crate::day03::part1::SolvePart1;
+
+    use super::{record, write_cast, write_text, RecordingOptions};
+
+    fn solver() -> SolvePart1 {
+        let grid = Grid::try_from(vec!["..#", "#..", ".#."]).unwrap();
+        SolvePart1::new(grid, geom::Vec2::new(1, 1)).unwrap()
+    }
+
+    fn options() -> RecordingOptions {
+        RecordingOptions {
+            size: Vec2::new(4, 3),
+            ..RecordingOptions::default()
+        }
+    }
+
+    #[test]
+    fn it_records_every_step() {
+        let frames = record(&mut solver(), &options());
+        let steps: Vec<_> = frames.iter().map(|f| f.step).collect();
+        assert_eq!(steps, vec![0, 1, 2, 3]);
+
+        let mut text = Vec::new();
+        write_text(&mut text, &frames[..2]).unwrap();
+        assert_eq!(
+            String::from_utf8(text).unwrap(),
+            "--- step 0 ---\n\n--- step 1 ---\nO\n"
+        );
+    }
+
+    #[test]
+    fn it_skips_and_limits_frames() {
+        let options = RecordingOptions {
+            every: 2,
+            ..options()
+        };
+        let steps: Vec<_> = record(&mut solver(), &options)
+            .iter()
+            .map(|f| f.step)
+            .collect();
+        assert_eq!(steps, vec![0, 2, 3]);
+
+        let options = RecordingOptions {
+            max_frames: 2,
+            ..options
+        };
+        let steps: Vec<_> = record(&mut solver(), &options)
+            .iter()
+            .map(|f| f.step)
+            .collect();
+        assert_eq!(steps, vec![0, 2, 3]);
+
+        let options = RecordingOptions {
+            every: 1,
+            max_frames: 1,
+            ..options
+        };
+        let steps: Vec<_> = record(&mut solver(), &options)
+            .iter()
+            .map(|f| f.step)
+            .collect();
+        assert_eq!(steps, vec![0, 3]);
+    }
+
+    #[test]
+    fn it_writes_asciicast() {
+        let options = RecordingOptions {
+            fps: 2,
+            ..options()
+        };
+        let frames = record(&mut solver(), &options);
+
+        let mut cast = Vec::new();
+        write_cast(&mut cast, &frames, &options, "Day \"3\"").unwrap();
+        let cast = String::from_utf8(cast).unwrap();
+        let lines: Vec<_> = cast.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "{\"version\": 2, \"width\": 4, \"height\": 3, \"title\": \"Day \\\"3\\\"\"}"
+        );
+        assert_eq!(lines.len(), 5);
+        assert!(lines[2].starts_with("[0.500000, \"o\", \"\\u001b[H\\u001b[2J"));
+        assert!(lines[2].contains("\\r\\n"));
+    }
+}
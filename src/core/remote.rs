@@ -0,0 +1,501 @@
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use thiserror::Error;
+
+use super::{
+    puzzle::{Puzzle, PuzzlePart},
+    submissions::{Submission, SubmissionLog, Verdict, SUBMISSIONS_FILE},
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the adventofcode.com session cookie
+pub const SESSION_VAR: &str = "ADVENT_SESSION";
+
+const USER_AGENT: &str = "github.com/midouest/advent-of-code";
+
+#[derive(Debug, Error)]
+pub enum RemoteError {
+    #[error("Set {} to your adventofcode.com session token", SESSION_VAR)]
+    MissingSession,
+
+    #[error("Could not send request to {0}")]
+    Request(String, #[source] io::Error),
+
+    #[error("Request to {0} failed with status {1}")]
+    Status(String, u16),
+
+    #[error("Could not access {}", .0.display())]
+    Cache(PathBuf, #[source] io::Error),
+
+    #[error("Answer '{0}' is already known to be {1}")]
+    KnownWrong(String, Verdict),
+
+    #[error("An answer was submitted too recently, wait before trying again")]
+    RateLimited,
+
+    #[error("Could not find a verdict in the response to the submission")]
+    UnknownVerdict,
+}
+
+pub type Result<T> = std::result::Result<T, RemoteError>;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The transport used to talk to the Advent of Code server
+pub trait HttpBackend {
+    fn get(&self, url: &str, session: &str) -> io::Result<HttpResponse>;
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> io::Result<HttpResponse>;
+}
+
+/// Sends requests by shelling out to curl, which takes care of TLS
+#[derive(Debug, Default)]
+pub struct CurlBackend {}
+
+impl CurlBackend {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn request(&self, url: &str, session: &str, args: &[String]) -> io::Result<HttpResponse> {
+        let header = session_header(session)?;
+        let mut child = Command::new("curl")
+            .args(curl_args(url, args))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        // Other local users can read a process's arguments, so the session
+        // cookie goes to curl over stdin instead
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(header.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            return Err(io::Error::other(message));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing status code"))?;
+        let status = status
+            .trim()
+            .parse()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Invalid status code"))?;
+
+        Ok(HttpResponse {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// The arguments to run curl with. They never include the session token;
+/// curl reads the cookie header from stdin.
+fn curl_args(url: &str, args: &[String]) -> Vec<String> {
+    let mut curl_args: Vec<String> = [
+        "--silent",
+        "--show-error",
+        "--user-agent",
+        USER_AGENT,
+        "--header",
+        "@-",
+        "--write-out",
+        "\n%{http_code}",
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
+    curl_args.extend_from_slice(args);
+    curl_args.push(url.to_string());
+    curl_args
+}
+
+/// The cookie header for a session, one line for curl to read from stdin
+fn session_header(session: &str) -> io::Result<String> {
+    if session.contains(['\r', '\n']) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Session token must be a single line",
+        ));
+    }
+    Ok(format!("Cookie: session={}\n", session))
+}
+
+impl HttpBackend for CurlBackend {
+    fn get(&self, url: &str, session: &str) -> io::Result<HttpResponse> {
+        self.request(url, session, &[])
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> io::Result<HttpResponse> {
+        let args: Vec<_> = form
+            .iter()
+            .flat_map(|(key, value)| {
+                vec!["--data-urlencode".to_string(), format!("{}={}", key, value)]
+            })
+            .collect();
+        self.request(url, session, &args)
+    }
+}
+
+pub fn session_from_env() -> Result<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .filter(|session| !session.trim().is_empty())
+        .map(|session| session.trim().to_string())
+        .ok_or(RemoteError::MissingSession)
+}
+
+/// Downloads puzzle inputs into the input directory and submits answers,
+/// keeping a log of every verdict next to the inputs
+pub struct Client<B: HttpBackend> {
+    backend: B,
+    base_url: String,
+    session: String,
+    root: PathBuf,
+}
+
+impl<B> Client<B>
+where
+    B: HttpBackend,
+{
+    pub fn new(backend: B, session: String, root: PathBuf) -> Self {
+        Self {
+            backend,
+            base_url: BASE_URL.to_string(),
+            session,
+            root,
+        }
+    }
+
+    pub fn with_base_url(self, base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            ..self
+        }
+    }
+
+    /// The input directory that inputs are cached in
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Download the puzzle's input unless it is already cached, returning the
+    /// path of the cached input
+    pub fn fetch_input(&self, puzzle: &dyn Puzzle) -> Result<PathBuf> {
+        let path = self.root.join(puzzle.get_input_path());
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url,
+            puzzle.get_year(),
+            puzzle.get_day()
+        );
+        let response = self
+            .backend
+            .get(&url, &self.session)
+            .map_err(|err| RemoteError::Request(url.clone(), err))?;
+        if response.status != 200 {
+            return Err(RemoteError::Status(url, response.status));
+        }
+
+        write_file(&path, &response.body)?;
+        Ok(path)
+    }
+
+    /// Submit an answer and record its verdict. Answers that are already
+    /// known to be wrong are never sent.
+    pub fn submit(&self, puzzle: &dyn Puzzle, part: PuzzlePart, answer: &str) -> Result<Verdict> {
+        let year = puzzle.get_year();
+        let day = puzzle.get_day();
+
+        let log_path = self.root.join(SUBMISSIONS_FILE);
+        let mut log = SubmissionLog::open(&log_path)
+            .map_err(|err| RemoteError::Cache(log_path.clone(), err))?;
+
+        match log.known_verdict(year, day, part, answer) {
+            Some(Verdict::Correct) => return Ok(Verdict::Correct),
+            Some(verdict) => return Err(RemoteError::KnownWrong(answer.to_string(), verdict)),
+            None => (),
+        }
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let response = self
+            .backend
+            .post_form(&url, &self.session, &form)
+            .map_err(|err| RemoteError::Request(url.clone(), err))?;
+        if response.status != 200 {
+            return Err(RemoteError::Status(url, response.status));
+        }
+
+        if response.body.contains("You gave an answer too recently") {
+            return Err(RemoteError::RateLimited);
+        }
+        let verdict = Verdict::from_response(&response.body).ok_or(RemoteError::UnknownVerdict)?;
+
+        let submission = Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        };
+        log.record(submission)
+            .map_err(|err| RemoteError::Cache(log_path, err))?;
+
+        Ok(verdict)
+    }
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    let cache_err = |err| RemoteError::Cache(path.to_path_buf(), err);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(cache_err)?;
+    }
+    fs::write(path, contents).map_err(cache_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        io::{self, prelude::*},
+        net::{TcpListener, TcpStream},
+        path::{Path, PathBuf},
+        thread::{self, JoinHandle},
+    };
+
+    use crate::core::puzzle::PuzzlePart;
+    use crate::core::submissions::Verdict;
+    use crate::core::util::test_dir;
+    use crate::day01::Day01;
+
+    use super::{curl_args, session_header, Client, HttpBackend, HttpResponse, RemoteError};
+
+    /// Speaks just enough plain HTTP/1.1 to talk to the stand-in server
+    struct PlainHttpBackend {}
+
+    impl PlainHttpBackend {
+        fn request(
+            &self,
+            method: &str,
+            url: &str,
+            session: &str,
+            body: &str,
+        ) -> io::Result<HttpResponse> {
+            let rest = url.trim_start_matches("http://");
+            let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+
+            let mut stream = TcpStream::connect(host)?;
+            write!(
+                stream,
+                "{} {} HTTP/1.1\r\nHost: {}\r\nCookie: session={}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                method,
+                path,
+                host,
+                session,
+                body.len(),
+                body
+            )?;
+
+            let mut response = String::new();
+            stream.read_to_string(&mut response)?;
+            let (head, body) = response.split_once("\r\n\r\n").unwrap_or((&response, ""));
+            let status = head
+                .split(' ')
+                .nth(1)
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0);
+
+            Ok(HttpResponse {
+                status,
+                body: body.to_string(),
+            })
+        }
+    }
+
+    impl HttpBackend for PlainHttpBackend {
+        fn get(&self, url: &str, session: &str) -> io::Result<HttpResponse> {
+            self.request("GET", url, session, "")
+        }
+
+        fn post_form(
+            &self,
+            url: &str,
+            session: &str,
+            form: &[(&str, &str)],
+        ) -> io::Result<HttpResponse> {
+            let body: Vec<_> = form.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+            self.request("POST", url, session, &body.join("&"))
+        }
+    }
+
+    /// Serve one canned response per connection, returning each request
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let request = read_request(&mut stream);
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+
+    /// Read a request's head and as much of its body as it declares
+    fn read_request(stream: &mut TcpStream) -> String {
+        let mut request = Vec::new();
+        let mut buffer = [0; 4096];
+        loop {
+            let n = stream.read(&mut buffer).unwrap();
+            request.extend_from_slice(&buffer[..n]);
+
+            let text = String::from_utf8_lossy(&request).to_string();
+            if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                let length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .and_then(|length| length.parse().ok())
+                    .unwrap_or(0);
+                if n == 0 || body.len() >= length {
+                    return text;
+                }
+            } else if n == 0 {
+                return text;
+            }
+        }
+    }
+
+    fn temp_root(name: &str) -> PathBuf {
+        test_dir(&format!("remote-{}", name))
+    }
+
+    fn client(base_url: &str, root: &Path) -> Client<PlainHttpBackend> {
+        Client::new(PlainHttpBackend {}, "token".to_string(), root.to_path_buf())
+            .with_base_url(base_url)
+    }
+
+    #[test]
+    fn it_fetches_and_caches_input() {
+        let root = temp_root("fetch");
+        let (base_url, server) = serve(vec![(200, "1721\n979\n")]);
+        let client = client(&base_url, &root);
+
+        let path = client.fetch_input(&Day01::new()).unwrap();
+        assert_eq!(path, root.join("day01/expense_report.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1721\n979\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2020/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("Cookie: session=token"));
+
+        // The server has stopped, so this only succeeds by reading the cache
+        assert_eq!(client.fetch_input(&Day01::new()).unwrap(), path);
+    }
+
+    #[test]
+    fn it_reports_failed_downloads() {
+        let root = temp_root("fetch-failed");
+        let (base_url, server) = serve(vec![(404, "Not found")]);
+        let client = client(&base_url, &root);
+
+        let result = client.fetch_input(&Day01::new());
+        assert!(matches!(result, Err(RemoteError::Status(_, 404))));
+        assert!(!root.join("day01/expense_report.txt").exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn it_submits_answers_and_refuses_known_wrong_answers() {
+        let root = temp_root("submit");
+        let (base_url, server) = serve(vec![
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            ),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let client = client(&base_url, &root);
+        let day = Day01::new();
+
+        let verdict = client.submit(&day, PuzzlePart::One, "600000").unwrap();
+        assert_eq!(verdict, Verdict::TooHigh);
+
+        let result = client.submit(&day, PuzzlePart::One, "700000");
+        assert!(matches!(
+            result,
+            Err(RemoteError::KnownWrong(_, Verdict::TooHigh))
+        ));
+
+        let verdict = client.submit(&day, PuzzlePart::One, "514579").unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+
+        // Known correct answers are not submitted again
+        let verdict = client.submit(&day, PuzzlePart::One, "514579").unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2020/day/1/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=1&answer=600000"));
+        assert!(requests[1].ends_with("level=1&answer=514579"));
+    }
+
+    #[test]
+    fn it_keeps_the_session_out_of_curl_arguments() {
+        let session = "53616c7465645f5f";
+        let form = vec!["--data-urlencode".to_string(), "answer=42".to_string()];
+        let args = curl_args("https://adventofcode.com/2020/day/1/answer", &form);
+
+        assert!(args.iter().all(|arg| !arg.contains(session)));
+        assert!(args.windows(2).any(|pair| pair == ["--header", "@-"]));
+        assert_eq!(
+            session_header(session).unwrap(),
+            format!("Cookie: session={}\n", session)
+        );
+        assert!(session_header("abc\nHost: evil").is_err());
+    }
+}
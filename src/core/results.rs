@@ -0,0 +1,344 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, prelude::*},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use super::{
+    puzzle::PuzzlePart,
+    util::{escape_field, unescape_field},
+};
+
+/// Name of the results store, relative to the input directory
+pub const RESULTS_FILE: &str = "results.tsv";
+
+/// A completed solve of a puzzle part
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SolveResult {
+    pub year: usize,
+    pub day: usize,
+    pub part: PuzzlePart,
+    pub answer: String,
+    /// The time spent stepping the solver
+    pub duration: Duration,
+    pub input_hash: String,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+impl SolveResult {
+    /// Describe a solve that has just completed for the given input
+    pub fn now(
+        year: usize,
+        day: usize,
+        part: PuzzlePart,
+        answer: String,
+        duration: Duration,
+        input: &str,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or(0);
+
+        Self {
+            year,
+            day,
+            part,
+            answer,
+            duration,
+            input_hash: input_hash(input),
+            timestamp,
+        }
+    }
+
+    fn is_for(&self, year: usize, day: usize) -> bool {
+        self.year == year && self.day == day
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.year,
+            self.day,
+            self.part,
+            self.duration.as_micros(),
+            self.input_hash,
+            self.timestamp,
+            escape_field(&self.answer)
+        )
+    }
+
+    fn from_line(line: &str) -> io::Result<Self> {
+        let malformed = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Malformed result '{}'", line),
+            )
+        };
+
+        let fields: Vec<_> = line.splitn(7, '\t').collect();
+        if fields.len() != 7 {
+            return Err(malformed());
+        }
+
+        let number = |s: &str| s.parse().map_err(|_| malformed());
+
+        Ok(Self {
+            year: number(fields[0])? as usize,
+            day: number(fields[1])? as usize,
+            part: fields[2].parse().map_err(|_| malformed())?,
+            duration: Duration::from_micros(number(fields[3])?),
+            input_hash: fields[4].to_string(),
+            timestamp: number(fields[5])?,
+            answer: unescape_field(fields[6]).ok_or_else(malformed)?,
+        })
+    }
+}
+
+/// A past solve, flagged when its answer differs from an earlier solve of the
+/// same part with the same input
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PastSolve<'a> {
+    pub result: &'a SolveResult,
+    pub changed: bool,
+}
+
+/// A tab-separated, append-only record of every completed solve
+#[derive(Debug)]
+pub struct ResultLog {
+    path: PathBuf,
+    results: Vec<SolveResult>,
+    malformed: Vec<usize>,
+}
+
+impl ResultLog {
+    /// Open the log at the given path. A missing file is an empty log, and
+    /// malformed lines are skipped so that one bad line does not hide the
+    /// rest of the history.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let mut log = Self::empty(path);
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            match SolveResult::from_line(line) {
+                Ok(result) => log.results.push(result),
+                Err(_) => log.malformed.push(i + 1),
+            }
+        }
+        Ok(log)
+    }
+
+    /// A log with no past results that records to the given path
+    pub fn empty(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            results: Vec::new(),
+            malformed: Vec::new(),
+        }
+    }
+
+    /// The numbers of the lines that were skipped when opening the log,
+    /// counted from 1
+    pub fn malformed_lines(&self) -> &[usize] {
+        &self.malformed
+    }
+
+    pub fn results(&self) -> &[SolveResult] {
+        &self.results
+    }
+
+    pub fn record(&mut self, result: SolveResult) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", result.to_line())?;
+
+        self.results.push(result);
+        Ok(())
+    }
+
+    /// Get the most recent answer for a puzzle part, if it has been solved
+    pub fn latest_answer(&self, year: usize, day: usize, part: PuzzlePart) -> Option<&str> {
+        self.results
+            .iter()
+            .rev()
+            .find(|r| r.is_for(year, day) && r.part == part)
+            .map(|r| r.answer.as_str())
+    }
+
+    pub fn is_solved(&self, year: usize, day: usize, part: PuzzlePart) -> bool {
+        self.latest_answer(year, day, part).is_some()
+    }
+
+    /// The number of parts of a puzzle that have been solved
+    pub fn stars(&self, year: usize, day: usize) -> usize {
+        [PuzzlePart::One, PuzzlePart::Two]
+            .iter()
+            .filter(|&&part| self.is_solved(year, day, part))
+            .count()
+    }
+
+    /// Get the past solves of a puzzle, oldest first
+    pub fn history(&self, year: usize, day: usize) -> Vec<PastSolve<'_>> {
+        let results: Vec<_> = self
+            .results
+            .iter()
+            .filter(|r| r.is_for(year, day))
+            .collect();
+
+        results
+            .iter()
+            .enumerate()
+            .map(|(i, result)| PastSolve {
+                result,
+                changed: results[..i].iter().any(|earlier| {
+                    earlier.part == result.part
+                        && earlier.input_hash == result.input_hash
+                        && earlier.answer != result.answer
+                }),
+            })
+            .collect()
+    }
+}
+
+/// A stable hash of a puzzle's input, using 64-bit FNV-1a
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Format seconds since the Unix epoch as a UTC date and time
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+
+    // Convert days since the epoch to a civil date, from Howard Hinnant's
+    // date algorithms
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+
+    use crate::core::{puzzle::PuzzlePart, util::test_dir};
+
+    use super::{format_timestamp, input_hash, ResultLog, SolveResult};
+
+    fn result(part: PuzzlePart, answer: &str, input: &str, timestamp: u64) -> SolveResult {
+        SolveResult {
+            year: 2020,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            duration: Duration::from_micros(1500),
+            input_hash: input_hash(input),
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn it_persists_results() {
+        let dir = test_dir("results-persist");
+        let path = dir.join("results.tsv");
+
+        let mut log = ResultLog::open(&path).unwrap();
+        log.record(result(PuzzlePart::One, "514579", "1721", 1))
+            .unwrap();
+        log.record(result(PuzzlePart::Two, "241861950", "1721", 2))
+            .unwrap();
+
+        let log = ResultLog::open(&path).unwrap();
+        assert_eq!(
+            log.results(),
+            &[
+                result(PuzzlePart::One, "514579", "1721", 1),
+                result(PuzzlePart::Two, "241861950", "1721", 2),
+            ]
+        );
+        assert_eq!(log.stars(2020, 1), 2);
+        assert_eq!(log.stars(2020, 2), 0);
+    }
+
+    #[test]
+    fn it_flags_changed_answers_for_the_same_input() {
+        let dir = test_dir("results-changed");
+
+        let mut log = ResultLog::open(&dir.join("results.tsv")).unwrap();
+        log.record(result(PuzzlePart::One, "1", "a", 1)).unwrap();
+        log.record(result(PuzzlePart::One, "2", "b", 2)).unwrap();
+        log.record(result(PuzzlePart::Two, "2", "a", 3)).unwrap();
+        log.record(result(PuzzlePart::One, "3", "a", 4)).unwrap();
+        log.record(result(PuzzlePart::One, "3", "a", 5)).unwrap();
+
+        let changed: Vec<_> = log.history(2020, 1).iter().map(|s| s.changed).collect();
+        assert_eq!(changed, vec![false, false, false, true, true]);
+        assert_eq!(log.latest_answer(2020, 1, PuzzlePart::One), Some("3"));
+        assert_eq!(log.stars(2020, 1), 2);
+    }
+
+    #[test]
+    fn it_escapes_answers_and_skips_malformed_lines() {
+        let dir = test_dir("results-malformed");
+        let path = dir.join("results.tsv");
+
+        let mut log = ResultLog::open(&path).unwrap();
+        log.record(result(PuzzlePart::One, "#..\n.#\t\\", "a", 1))
+            .unwrap();
+        fs::write(&path, fs::read_to_string(&path).unwrap() + "2020\tone\n").unwrap();
+        log.record(result(PuzzlePart::Two, "2", "a", 2)).unwrap();
+
+        let log = ResultLog::open(&path).unwrap();
+        assert_eq!(
+            log.results(),
+            &[
+                result(PuzzlePart::One, "#..\n.#\t\\", "a", 1),
+                result(PuzzlePart::Two, "2", "a", 2),
+            ]
+        );
+        assert_eq!(log.malformed_lines(), &[2]);
+    }
+
+    #[test]
+    fn it_hashes_and_formats() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00");
+        assert_eq!(format_timestamp(1_606_798_800), "2020-12-01 05:00:00");
+        assert_eq!(format_timestamp(951_825_661), "2000-02-29 12:01:01");
+    }
+}
@@ -1,23 +1,58 @@
-use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Display,
+    io,
+    marker::PhantomData,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use cursive::{
     event::Event,
     event::EventResult,
     event::{Callback, Key},
+    theme::Effect,
+    traits::Nameable,
     views::Canvas,
-    views::{Dialog, TextView},
+    views::{Dialog, LinearLayout, TextView},
     Cursive, Printer,
 };
 
-use super::controller::{emit, run, take_events, Controller};
+use super::{
+    clipboard::send_to_clipboard,
+    controller::{emit, run, take_events, Controller},
+    history::History,
+    playback::{Frame, Playback},
+    puzzle::PuzzlePart,
+};
+
+/// The longest a frame may spend stepping a solver that is running to
+/// completion, so that the UI stays responsive
+const FINISH_FRAME_BUDGET: Duration = Duration::from_millis(25);
+
+const PLAYBACK_KEYS: &str =
+    "space pause  ,/. step  [/] jump  home/end rewind/live  +/- speed  enter finish  esc quit";
+
+/// The number of steps moved by a jump through the history
+const JUMP_STEPS: usize = 10;
+
+/// The name of the view in the solution dialog that says whether the solution
+/// was copied
+const COPY_STATUS: &str = "copy_status";
 
-pub fn solve<S, T>(solver: S, c: Rc<RefCell<Cursive>>)
+/// Show a solver stepping towards its solution, returning what to do once the
+/// solver view is closed along with the solution, if it was found. Pass the
+/// other part of the puzzle to offer running it next.
+pub fn solve<S, T>(solver: S, c: Rc<RefCell<Cursive>>, other_part: Option<PuzzlePart>) -> SolverExit
 where
     S: Solver<T> + 'static,
-    T: std::fmt::Display,
+    T: std::fmt::Display + 'static,
 {
-    let controller = SolverController::new(solver);
-    controller.run(c);
+    let mut controller = SolverController::new(solver);
+    if let Some(part) = other_part {
+        controller = controller.with_other_part(part);
+    }
+    controller.run(c)
 }
 
 pub trait Solver<T> {
@@ -26,6 +61,34 @@ pub trait Solver<T> {
     fn step(&mut self);
     fn draw(&self, printer: &Printer);
 
+    /// The number of steps taken so far, if the solver keeps count
+    fn steps_taken(&self) -> Option<usize> {
+        None
+    }
+
+    /// An estimate of the total number of steps needed to find the solution
+    fn estimated_steps(&self) -> Option<usize> {
+        None
+    }
+
+    /// The fraction of the work that is done, between 0 and 1
+    fn progress(&self) -> Option<f64> {
+        if self.is_done() {
+            return Some(1.0);
+        }
+
+        match (self.steps_taken(), self.estimated_steps()) {
+            (Some(taken), Some(total)) if total > 0 => Some((taken as f64 / total as f64).min(1.0)),
+            _ => None,
+        }
+    }
+
+    /// Copy the solver's current state so that it can be revisited later.
+    /// Solvers that implement `Clone` can opt in with `snapshot_of(self)`.
+    fn snapshot(&self) -> Option<Box<dyn Solver<T>>> {
+        None
+    }
+
     fn solve(&mut self) -> Option<T> {
         while !self.is_done() {
             self.step();
@@ -35,6 +98,7 @@ pub trait Solver<T> {
 
     fn with_done<U, F>(&self, f: F) -> Option<U>
     where
+        Self: Sized,
         F: Fn() -> U,
     {
         if self.is_done() {
@@ -44,7 +108,10 @@ pub trait Solver<T> {
         }
     }
 
-    fn with_done_some<U>(&self, u: U) -> Option<U> {
+    fn with_done_some<U>(&self, u: U) -> Option<U>
+    where
+        Self: Sized,
+    {
         if self.is_done() {
             Some(u)
         } else {
@@ -53,8 +120,204 @@ pub trait Solver<T> {
     }
 }
 
+impl<S, T> Solver<T> for Box<S>
+where
+    S: Solver<T> + ?Sized,
+{
+    fn is_done(&self) -> bool {
+        self.as_ref().is_done()
+    }
+
+    fn solution(&self) -> Option<T> {
+        self.as_ref().solution()
+    }
+
+    fn step(&mut self) {
+        self.as_mut().step()
+    }
+
+    fn draw(&self, printer: &Printer) {
+        self.as_ref().draw(printer)
+    }
+
+    fn steps_taken(&self) -> Option<usize> {
+        self.as_ref().steps_taken()
+    }
+
+    fn estimated_steps(&self) -> Option<usize> {
+        self.as_ref().estimated_steps()
+    }
+
+    fn progress(&self) -> Option<f64> {
+        self.as_ref().progress()
+    }
+
+    fn snapshot(&self) -> Option<Box<dyn Solver<T>>> {
+        self.as_ref().snapshot()
+    }
+}
+
+/// Snapshot a solver by cloning it
+pub fn snapshot_of<S, T>(solver: &S) -> Option<Box<dyn Solver<T>>>
+where
+    S: Solver<T> + Clone + 'static,
+{
+    Some(Box::new(solver.clone()))
+}
+
+/// Step a solver to completion, calling `report` with a description of its
+/// progress at most once per interval
+pub fn solve_reporting<S, T, F>(solver: &mut S, interval: Duration, mut report: F) -> Option<T>
+where
+    S: Solver<T> + ?Sized,
+    F: FnMut(&str),
+{
+    let mut steps = 0;
+    let mut last_report = Instant::now();
+
+    while !solver.is_done() {
+        solver.step();
+        steps += 1;
+
+        if last_report.elapsed() >= interval {
+            report(&format_progress(solver, steps));
+            last_report = Instant::now();
+        }
+    }
+
+    solver.solution()
+}
+
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+/// Describe a solver's progress. The counted steps are used when the solver
+/// does not keep count itself.
+pub fn format_progress<S, T>(solver: &S, counted_steps: usize) -> String
+where
+    S: Solver<T> + ?Sized,
+{
+    let steps = solver.steps_taken().unwrap_or(counted_steps);
+    let mut text = if solver.is_done() {
+        format!("Done after {} steps", steps)
+    } else {
+        match solver.estimated_steps() {
+            Some(total) => format!("Step {} of ~{}", steps, total),
+            None => format!("Step {}", steps),
+        }
+    };
+
+    if let Some(progress) = solver.progress() {
+        let filled = (progress * PROGRESS_BAR_WIDTH as f64).round() as usize;
+        text += &format!(
+            " [{}{}] {:.0}%",
+            "#".repeat(filled),
+            ".".repeat(PROGRESS_BAR_WIDTH - filled),
+            progress * 100.0
+        );
+    }
+
+    text
+}
+
+/// Box a solver, erasing its type and formatting its solution as a string
+pub fn boxed<S, T>(solver: S) -> Box<dyn Solver<String>>
+where
+    S: Solver<T> + 'static,
+    T: Display + 'static,
+{
+    Box::new(DisplaySolver::new(solver))
+}
+
+pub struct DisplaySolver<S, T>
+where
+    S: Solver<T>,
+{
+    solver: S,
+    _phantom: PhantomData<T>,
+}
+
+impl<S, T> DisplaySolver<S, T>
+where
+    S: Solver<T>,
+    T: Display,
+{
+    pub fn new(solver: S) -> Self {
+        Self {
+            solver,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Solver<String> for DisplaySolver<S, T>
+where
+    S: Solver<T>,
+    T: Display + 'static,
+{
+    fn is_done(&self) -> bool {
+        self.solver.is_done()
+    }
+
+    fn solution(&self) -> Option<String> {
+        self.solver.solution().map(|solution| solution.to_string())
+    }
+
+    fn step(&mut self) {
+        self.solver.step()
+    }
+
+    fn draw(&self, printer: &Printer) {
+        self.solver.draw(printer)
+    }
+
+    fn steps_taken(&self) -> Option<usize> {
+        self.solver.steps_taken()
+    }
+
+    fn estimated_steps(&self) -> Option<usize> {
+        self.solver.estimated_steps()
+    }
+
+    fn progress(&self) -> Option<f64> {
+        self.solver.progress()
+    }
+
+    fn snapshot(&self) -> Option<Box<dyn Solver<String>>> {
+        self.solver
+            .snapshot()
+            .map(|snapshot| boxed::<_, T>(snapshot))
+    }
+}
+
+/// What the user chose to do after closing a solver view
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SolverOutcome {
+    Back,
+    RunAgain,
+    RunOtherPart,
+}
+
+/// How a solver view was closed, along with the solution if one was found
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SolverExit {
+    pub outcome: SolverOutcome,
+    pub solution: Option<String>,
+    /// The time spent stepping the solver
+    pub duration: Duration,
+}
+
 pub enum SolverEvent {
     Stop,
+    Close(SolverOutcome),
+    CopySolution,
+    TogglePause,
+    Step(usize),
+    Back(usize),
+    Rewind,
+    Live,
+    Faster,
+    Slower,
+    Finish,
 }
 
 pub struct SolverController<S, T>
@@ -64,46 +327,121 @@ where
     is_running: bool,
     is_solved: bool,
     state: Rc<RefCell<S>>,
+    steps: Rc<Cell<usize>>,
+    playback: Rc<Cell<Playback>>,
+    history: Rc<RefCell<History<T>>>,
+    other_part: Option<PuzzlePart>,
+    outcome: SolverOutcome,
+    solution: Option<String>,
+    started: Instant,
+    solve_time: Duration,
+    /// Sends the solution to the clipboard
+    clipboard: fn(&str) -> io::Result<()>,
     _phantom: PhantomData<T>,
 }
 
 impl<S, T> SolverController<S, T>
 where
     S: Solver<T> + 'static,
-    T: std::fmt::Display,
+    T: std::fmt::Display + 'static,
 {
     pub fn new(solver: S) -> Self {
         Self {
             is_running: true,
             is_solved: false,
             state: Rc::new(RefCell::new(solver)),
+            steps: Rc::new(Cell::new(0)),
+            playback: Rc::new(Cell::new(Playback::new())),
+            history: Rc::new(RefCell::new(History::default())),
+            other_part: None,
+            outcome: SolverOutcome::Back,
+            solution: None,
+            started: Instant::now(),
+            solve_time: Duration::default(),
+            clipboard: send_to_clipboard,
             _phantom: PhantomData,
         }
     }
 
-    pub fn run(self, c: Rc<RefCell<Cursive>>) {
-        run::<SolverController<S, T>, SolverEvent>(self, c)
+    /// Offer to run the other part of the puzzle once the solution is found
+    pub fn with_other_part(self, part: PuzzlePart) -> Self {
+        Self {
+            other_part: Some(part),
+            ..self
+        }
+    }
+
+    pub fn run(self, c: Rc<RefCell<Cursive>>) -> SolverExit {
+        let controller = run::<SolverController<S, T>, SolverEvent>(self, c);
+        SolverExit {
+            outcome: controller.outcome,
+            solution: controller.solution,
+            duration: controller.solve_time,
+        }
     }
 }
 
 impl<S, T> Controller for SolverController<S, T>
 where
     S: Solver<T> + 'static,
-    T: ToString,
+    T: ToString + 'static,
 {
     fn show(&mut self, c: Rc<RefCell<Cursive>>) {
         let mut siv = c.borrow_mut();
+        self.started = Instant::now();
 
+        let steps = Rc::clone(&self.steps);
+        let playback = Rc::clone(&self.playback);
+        let history = Rc::clone(&self.history);
         let canvas = Canvas::new(Rc::clone(&self.state))
             .with_required_size(|_, constraints| constraints)
-            .with_draw(|s, printer| {
-                s.borrow().draw(printer);
+            .with_draw(move |s, printer| {
+                let solver = s.borrow();
+                let history = history.borrow();
+                let height = printer.size.y.saturating_sub(1);
+                let cropped = printer.cropped((printer.size.x, height));
+
+                let progress = match history.viewing() {
+                    Some(snapshot) => {
+                        snapshot.draw(&cropped);
+                        format!(
+                            "Rewound to step {} of {}",
+                            history.position(),
+                            history.live_position()
+                        )
+                    }
+                    None => {
+                        solver.draw(&cropped);
+                        format_progress(&*solver, steps.get())
+                    }
+                };
+                let mode = format!("[{}]  {}", playback.get(), PLAYBACK_KEYS);
+                printer.with_effect(Effect::Reverse, |printer| {
+                    let width = printer.size.x;
+                    let line = if progress.len() + mode.len() < width {
+                        format!("{}{:>pad$}", progress, mode, pad = width - progress.len())
+                    } else {
+                        format!("{:width$}", progress, width = width)
+                    };
+                    printer.print((0, height), &line);
+                });
             })
-            .with_on_event(|_, event| match event {
-                Event::Key(Key::Esc) => EventResult::Consumed(Some(Callback::from_fn(|c| {
-                    emit(c, SolverEvent::Stop);
-                }))),
-                _ => EventResult::Ignored,
+            .with_on_event(|_, event| {
+                let event = match event {
+                    Event::Key(Key::Esc) => SolverEvent::Stop,
+                    Event::Char(' ') => SolverEvent::TogglePause,
+                    Event::Char('.') | Event::Key(Key::Right) => SolverEvent::Step(1),
+                    Event::Char(']') => SolverEvent::Step(JUMP_STEPS),
+                    Event::Char(',') | Event::Key(Key::Left) => SolverEvent::Back(1),
+                    Event::Char('[') => SolverEvent::Back(JUMP_STEPS),
+                    Event::Key(Key::Home) => SolverEvent::Rewind,
+                    Event::Key(Key::End) => SolverEvent::Live,
+                    Event::Char('+') | Event::Char('=') => SolverEvent::Faster,
+                    Event::Char('-') => SolverEvent::Slower,
+                    Event::Key(Key::Enter) | Event::Char('f') => SolverEvent::Finish,
+                    _ => return EventResult::Ignored,
+                };
+                EventResult::Consumed(Some(Callback::from_fn_once(move |c| emit(c, event))))
             });
 
         siv.add_fullscreen_layer(canvas);
@@ -111,27 +449,90 @@ where
 
     fn process_events(&mut self, c: Rc<RefCell<Cursive>>) -> bool {
         let events = take_events(&mut c.borrow_mut());
+        let mut playback = self.playback.get();
         for event in events {
             match event {
                 SolverEvent::Stop => self.is_running = false,
+                SolverEvent::Close(outcome) => {
+                    // Close the solution dialog along with the solver view
+                    c.borrow_mut().pop_layer();
+                    self.outcome = outcome;
+                    self.is_running = false;
+                }
+                SolverEvent::CopySolution => self.copy_solution(Rc::clone(&c)),
+                SolverEvent::TogglePause => playback.toggle_pause(),
+                SolverEvent::Step(steps) => playback.step_by(steps),
+                SolverEvent::Back(steps) => {
+                    playback.pause();
+                    self.history.borrow_mut().back(steps);
+                }
+                SolverEvent::Rewind => {
+                    playback.pause();
+                    self.history.borrow_mut().to_start();
+                }
+                SolverEvent::Live => self.history.borrow_mut().to_live(),
+                SolverEvent::Faster => playback.faster(),
+                SolverEvent::Slower => playback.slower(),
+                SolverEvent::Finish => {
+                    self.history.borrow_mut().to_live();
+                    playback.finish();
+                }
             }
         }
+        let frame = playback.next_frame();
+        self.playback.set(playback);
 
         if self.is_running {
+            // Only borrowed while stepping, since copying the solution redraws
+            // the solver view, which reads the history
+            let mut history = self.history.borrow_mut();
+
+            // Frames move through the history first, so that scrubbing still
+            // works once the live solver is done
+            let live_steps = match frame {
+                Frame::Steps(n) => Some(history.forward(n)),
+                Frame::Finish => None,
+            };
+
             let mut state = self.state.borrow_mut();
             if state.is_done() {
                 if !self.is_solved {
-                    let solution = state.solution().unwrap().to_string();
-                    c.borrow_mut().add_layer(
-                        Dialog::new()
-                            .title("Solution")
-                            .content(TextView::new(solution))
-                            .dismiss_button("Ok"),
-                    );
+                    self.solution = state.solution().map(|solution| solution.to_string());
+                    let dialog = self.build_solution_dialog(self.steps.get());
+                    c.borrow_mut().add_layer(dialog);
                     self.is_solved = true;
                 }
             } else {
-                state.step();
+                let mut steps = self.steps.get();
+                let mut solve_time = self.solve_time;
+                let mut step = |state: &mut S| {
+                    if let Some(snapshot) = state.snapshot() {
+                        history.record(snapshot);
+                    }
+                    let start = Instant::now();
+                    state.step();
+                    solve_time += start.elapsed();
+                    steps += 1;
+                };
+
+                match live_steps {
+                    Some(live_steps) => {
+                        for _ in 0..live_steps {
+                            if state.is_done() {
+                                break;
+                            }
+                            step(&mut state);
+                        }
+                    }
+                    None => {
+                        let start = Instant::now();
+                        while !state.is_done() && start.elapsed() < FINISH_FRAME_BUDGET {
+                            step(&mut state);
+                        }
+                    }
+                }
+                self.steps.set(steps);
+                self.solve_time = solve_time;
             }
         } else {
             c.borrow_mut().pop_layer();
@@ -140,3 +541,163 @@ where
         return self.is_running;
     }
 }
+
+impl<S, T> SolverController<S, T>
+where
+    S: Solver<T>,
+{
+    fn build_solution_dialog(&self, steps: usize) -> Dialog {
+        let mut text = match &self.solution {
+            Some(solution) => format!("Solution: {}\n", solution),
+            None => "No solution was found\n".to_string(),
+        };
+        text += &format!(
+            "\nSteps:       {}\nElapsed:     {:.2?}\nSolver time: {:.2?}",
+            steps,
+            self.started.elapsed(),
+            self.solve_time
+        );
+
+        let close = |outcome| move |c: &mut Cursive| emit(c, SolverEvent::Close(outcome));
+        let mut dialog = Dialog::new().title("Solution").content(
+            LinearLayout::vertical()
+                .child(TextView::new(text))
+                .child(TextView::new("").with_name(COPY_STATUS)),
+        );
+        if self.solution.is_some() {
+            dialog = dialog.button("Copy", |c| emit(c, SolverEvent::CopySolution));
+        }
+        dialog = dialog.button("Run again", close(SolverOutcome::RunAgain));
+        if let Some(part) = self.other_part {
+            dialog = dialog.button(
+                format!("Run part {}", part),
+                close(SolverOutcome::RunOtherPart),
+            );
+        }
+        dialog
+            .button("Back to hub", close(SolverOutcome::Back))
+            .dismiss_button("Close")
+    }
+
+    /// Send the solution to the clipboard and say so in the solution dialog.
+    /// The screen is refreshed first so that the escape sequence is not
+    /// written in the middle of a frame.
+    fn copy_solution(&self, c: Rc<RefCell<Cursive>>) {
+        let solution = match &self.solution {
+            Some(solution) => solution,
+            None => return,
+        };
+
+        let mut c = c.borrow_mut();
+        c.refresh();
+        let status = match (self.clipboard)(solution) {
+            Ok(()) => "Sent to the clipboard (OSC 52)".to_string(),
+            Err(err) => format!("Could not send to the clipboard: {}", err),
+        };
+        c.call_on_name(COPY_STATUS, |view: &mut TextView| {
+            view.set_content(format!("\n{}", status));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use cursive::{views::TextView, Cursive, Printer};
+
+    use crate::core::controller::Controller;
+
+    use super::{format_progress, snapshot_of, Solver, SolverController, SolverEvent, COPY_STATUS};
+
+    #[derive(Clone)]
+    struct CountDown {
+        remaining: usize,
+        estimate: Option<usize>,
+    }
+
+    impl Solver<usize> for CountDown {
+        fn is_done(&self) -> bool {
+            self.remaining == 0
+        }
+
+        fn solution(&self) -> Option<usize> {
+            self.with_done_some(0)
+        }
+
+        fn step(&mut self) {
+            self.remaining -= 1;
+        }
+
+        fn draw(&self, _printer: &Printer) {}
+
+        fn steps_taken(&self) -> Option<usize> {
+            self.estimate.map(|total| total - self.remaining)
+        }
+
+        fn estimated_steps(&self) -> Option<usize> {
+            self.estimate
+        }
+
+        fn snapshot(&self) -> Option<Box<dyn Solver<usize>>> {
+            snapshot_of(self)
+        }
+    }
+
+    #[test]
+    fn it_reports_progress() {
+        let mut solver = CountDown {
+            remaining: 4,
+            estimate: Some(4),
+        };
+        solver.step();
+        assert_eq!(solver.progress(), Some(0.25));
+        assert_eq!(
+            format_progress(&solver, 0),
+            "Step 1 of ~4 [#####...............] 25%"
+        );
+
+        solver.solve();
+        assert_eq!(
+            format_progress(&solver, 0),
+            "Done after 4 steps [####################] 100%"
+        );
+    }
+
+    #[test]
+    fn it_falls_back_to_counted_steps() {
+        let solver = CountDown {
+            remaining: 4,
+            estimate: None,
+        };
+        assert_eq!(solver.progress(), None);
+        assert_eq!(format_progress(&solver, 3), "Step 3");
+    }
+
+    #[test]
+    fn it_copies_the_solution_while_rewound() {
+        let siv = Rc::new(RefCell::new(Cursive::dummy()));
+        let mut controller = SolverController::new(CountDown {
+            remaining: 3,
+            estimate: Some(3),
+        });
+        controller.clipboard = |_| Ok(());
+        controller.show(Rc::clone(&siv));
+
+        let mut process = |event| {
+            siv.borrow_mut().set_user_data(vec![event]);
+            controller.process_events(Rc::clone(&siv))
+        };
+        assert!(process(SolverEvent::Finish));
+        assert!(process(SolverEvent::Live));
+        assert!(process(SolverEvent::Back(1)));
+        assert!(process(SolverEvent::CopySolution));
+
+        let status = siv
+            .borrow_mut()
+            .call_on_name(COPY_STATUS, |view: &mut TextView| {
+                view.get_content().source().to_string()
+            });
+        assert_eq!(status, Some("\nSent to the clipboard (OSC 52)".to_string()));
+    }
+}
@@ -0,0 +1,316 @@
+use std::{
+    collections::{hash_map, HashMap},
+    iter::FromIterator,
+};
+
+use super::{
+    geom::{Point, Vec2},
+    grid::{Grid, Neighborhood},
+};
+
+/// The smallest rectangle containing a set of points, with inclusive corners
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Bounds {
+    pub min: Point<isize>,
+    pub max: Point<isize>,
+}
+
+impl Bounds {
+    pub fn new(min: Point<isize>, max: Point<isize>) -> Self {
+        Self { min, max }
+    }
+
+    /// The number of columns, which is 0 if the minimum is right of the
+    /// maximum
+    pub fn width(&self) -> usize {
+        span(self.min.x, self.max.x)
+    }
+
+    /// The number of rows, which is 0 if the minimum is below the maximum
+    pub fn height(&self) -> usize {
+        span(self.min.y, self.max.y)
+    }
+
+    pub fn contains(&self, point: Point<isize>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Grow the bounds to include the given point
+    pub fn extend(&mut self, point: Point<isize>) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    /// Whether the point lies on the edge of the bounds
+    fn is_on_edge(&self, point: Point<isize>) -> bool {
+        point.x == self.min.x
+            || point.x == self.max.x
+            || point.y == self.min.y
+            || point.y == self.max.y
+    }
+}
+
+/// The number of values from min to max inclusive
+fn span(min: isize, max: isize) -> usize {
+    if min > max {
+        0
+    } else {
+        max.abs_diff(min) + 1
+    }
+}
+
+/// An unbounded grid that only stores the cells that have been set, for
+/// simulations whose active area keeps growing
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point<isize>, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect the cells of a dense grid that match the predicate, keeping
+    /// their positions
+    pub fn from_grid<P>(grid: &Grid<T>, predicate: P) -> Self
+    where
+        T: Clone,
+        P: Fn(&T) -> bool,
+    {
+        grid.enumerate()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|((x, y), cell)| (Point::new(x as isize, y as isize), cell.clone()))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The bounding box of every set cell, or `None` if the grid is empty
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn get(&self, point: Point<isize>) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point<isize>) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point<isize>) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Set a cell, returning its previous value
+    pub fn insert(&mut self, point: Point<isize>, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(point),
+            None => self.bounds = Some(Bounds::new(point, point)),
+        }
+        self.cells.insert(point, value)
+    }
+
+    /// Clear a cell, returning its value. The bounds shrink if the cell was on
+    /// their edge.
+    pub fn remove(&mut self, point: Point<isize>) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+        if self.bounds.is_some_and(|bounds| bounds.is_on_edge(point)) {
+            self.bounds = self.compute_bounds();
+        }
+        Some(value)
+    }
+
+    /// Iterate over the set cells in no particular order
+    pub fn iter(&self) -> hash_map::Iter<'_, Point<isize>, T> {
+        self.cells.iter()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point<isize>> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Get the set neighbors in the given neighborhood along with their
+    /// positions, in the order of the neighborhood's offsets
+    pub fn neighbors_in(
+        &self,
+        point: Point<isize>,
+        neighborhood: &Neighborhood,
+    ) -> Vec<(Point<isize>, &T)> {
+        neighborhood
+            .offsets()
+            .iter()
+            .map(|&offset| point + Vec2::from(offset))
+            .filter_map(|neighbor| self.get(neighbor).map(|cell| (neighbor, cell)))
+            .collect()
+    }
+
+    /// Copy the cells inside the given bounds into a dense grid, filling the
+    /// cells that are not set with the default. The grid's origin is the
+    /// minimum corner of the bounds. Bounds whose minimum is past their
+    /// maximum give an empty grid.
+    pub fn to_grid_within(&self, bounds: Bounds, default: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut grid = Grid::new(bounds.width(), bounds.height(), default);
+        for (&point, cell) in self.iter().filter(|(&point, _)| bounds.contains(point)) {
+            let offset = point - bounds.min;
+            grid[(offset.x as usize, offset.y as usize)] = cell.clone();
+        }
+        grid
+    }
+
+    /// Copy the set cells into a dense grid covering their bounds. An empty
+    /// sparse grid becomes an empty dense grid.
+    pub fn to_grid(&self, default: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        match self.bounds {
+            Some(bounds) => self.to_grid_within(bounds, default),
+            None => Grid::new(0, 0, default),
+        }
+    }
+
+    fn compute_bounds(&self) -> Option<Bounds> {
+        let mut points = self.points();
+        let first = points.next()?;
+        let mut bounds = Bounds::new(first, first);
+        points.for_each(|point| bounds.extend(point));
+        Some(bounds)
+    }
+}
+
+impl<T> FromIterator<(Point<isize>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point<isize>, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use cursive::Vec2;
+
+    use crate::core::{
+        geom::Point,
+        grid::{Grid, Neighborhood},
+        offscreen::render,
+    };
+
+    use super::{Bounds, SparseGrid};
+
+    #[test]
+    fn it_tracks_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Point::new(2, -1), 'a');
+        grid.insert(Point::new(-3, 4), 'b');
+        grid.insert(Point::new(0, 0), 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds::new(Point::new(-3, -1), Point::new(2, 4)))
+        );
+
+        assert_eq!(grid.remove(Point::new(0, 0)), Some('c'));
+        assert_eq!(grid.remove(Point::new(0, 0)), None);
+        assert_eq!(grid.remove(Point::new(-3, 4)), Some('b'));
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds::new(Point::new(2, -1), Point::new(2, -1)))
+        );
+
+        grid.remove(Point::new(2, -1));
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn it_gets_set_neighbors() {
+        let grid: SparseGrid<_> = vec![
+            (Point::new(-1, -1), 'a'),
+            (Point::new(0, -1), 'b'),
+            (Point::new(5, 5), 'c'),
+            (Point::new(1, 0), 'd'),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            grid.neighbors_in(Point::new(0, 0), &Neighborhood::Moore),
+            vec![
+                (Point::new(-1, -1), &'a'),
+                (Point::new(0, -1), &'b'),
+                (Point::new(1, 0), &'d'),
+            ]
+        );
+        assert_eq!(
+            grid.neighbors_in(Point::new(0, 0), &Neighborhood::VonNeumann),
+            vec![(Point::new(0, -1), &'b'), (Point::new(1, 0), &'d')]
+        );
+    }
+
+    #[test]
+    fn it_converts_to_and_from_dense_grids() {
+        let dense = Grid::try_from(vec![".#.", "..#", "###"]).unwrap();
+        let mut sparse = SparseGrid::from_grid(&dense, |&c| c == '#');
+        assert_eq!(sparse.len(), 5);
+        assert_eq!(sparse.get(Point::new(1, 0)), Some(&'#'));
+        assert_eq!(sparse.get(Point::new(0, 0)), None);
+        assert_eq!(
+            sparse.to_grid('.'),
+            Grid::try_from(vec![".#.", "..#", "###"]).unwrap()
+        );
+
+        sparse.insert(Point::new(-1, 3), '#');
+        assert_eq!(
+            sparse.to_grid('.'),
+            Grid::try_from(vec!["..#.", "...#", ".###", "#..."]).unwrap()
+        );
+
+        let window = Bounds::new(Point::new(1, 1), Point::new(2, 2));
+        assert_eq!(
+            sparse.to_grid_within(window, '.'),
+            Grid::try_from(vec![".#", "##"]).unwrap()
+        );
+        assert_eq!(SparseGrid::<char>::new().to_grid('.').width(), 0);
+    }
+
+    #[test]
+    fn it_draws_an_empty_grid() {
+        let sparse = SparseGrid::<char>::new();
+        let inverted = Bounds::new(Point::new(2, 2), Point::new(-2, -2));
+        assert_eq!(sparse.to_grid_within(inverted, '.').width(), 0);
+
+        let frame = render(Vec2::new(3, 2), |printer| {
+            for (y, row) in sparse.to_grid('.').rows().enumerate() {
+                printer.print((0, y), &row.iter().collect::<String>());
+            }
+        });
+        assert_eq!(frame.text(), "");
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, prelude::*},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use super::{
+    puzzle::PuzzlePart,
+    util::{escape_field, unescape_field},
+};
+
+/// Name of the submission log, relative to the input directory
+pub const SUBMISSIONS_FILE: &str = "submissions.tsv";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    pub fn is_correct(&self) -> bool {
+        *self == Verdict::Correct
+    }
+
+    /// Read the verdict from the page returned after submitting an answer
+    pub fn from_response(body: &str) -> Option<Self> {
+        if body.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                Some(Verdict::TooHigh)
+            } else if body.contains("your answer is too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Incorrect)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Self> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            _ => Err(invalid_data(format!("Unknown verdict '{}'", s))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Submission {
+    pub year: usize,
+    pub day: usize,
+    pub part: PuzzlePart,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Submission {
+    fn is_for(&self, year: usize, day: usize, part: PuzzlePart) -> bool {
+        self.year == year && self.day == day && self.part == part
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}",
+            self.year,
+            self.day,
+            self.part,
+            self.verdict,
+            escape_field(&self.answer)
+        )
+    }
+
+    fn from_line(line: &str) -> io::Result<Self> {
+        let fields: Vec<_> = line.splitn(5, '\t').collect();
+        if fields.len() != 5 {
+            return Err(invalid_data(format!("Malformed submission '{}'", line)));
+        }
+
+        let number = |s: &str| {
+            s.parse()
+                .map_err(|_| invalid_data(format!("Malformed submission '{}'", line)))
+        };
+
+        Ok(Self {
+            year: number(fields[0])?,
+            day: number(fields[1])?,
+            part: fields[2]
+                .parse()
+                .map_err(|_| invalid_data(format!("Malformed submission '{}'", line)))?,
+            verdict: fields[3].parse()?,
+            answer: unescape_field(fields[4])
+                .ok_or_else(|| invalid_data(format!("Malformed submission '{}'", line)))?,
+        })
+    }
+}
+
+/// A tab-separated, append-only record of every answer that has been submitted
+/// along with its verdict
+#[derive(Debug)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+    malformed: Vec<usize>,
+}
+
+impl SubmissionLog {
+    /// Open the log at the given path. A missing file is an empty log, and
+    /// malformed lines are skipped so that one bad line does not block every
+    /// later submission.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let mut log = Self {
+            path: path.to_path_buf(),
+            submissions: Vec::new(),
+            malformed: Vec::new(),
+        };
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            match Submission::from_line(line) {
+                Ok(submission) => log.submissions.push(submission),
+                Err(_) => log.malformed.push(i + 1),
+            }
+        }
+        Ok(log)
+    }
+
+    /// The numbers of the lines that were skipped when opening the log,
+    /// starting from 1
+    pub fn malformed_lines(&self) -> &[usize] {
+        &self.malformed
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    pub fn record(&mut self, submission: Submission) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", submission.to_line())?;
+
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Get the verdict for an answer if it can be known without submitting it,
+    /// either because the same answer was submitted before, because the
+    /// correct answer is already known, or because the answer lies beyond one
+    /// that was rejected as too high or too low
+    pub fn known_verdict(
+        &self,
+        year: usize,
+        day: usize,
+        part: PuzzlePart,
+        answer: &str,
+    ) -> Option<Verdict> {
+        let previous: Vec<_> = self
+            .submissions
+            .iter()
+            .filter(|s| s.is_for(year, day, part))
+            .collect();
+
+        if let Some(s) = previous.iter().find(|s| s.answer == answer) {
+            return Some(s.verdict);
+        }
+
+        if previous.iter().any(|s| s.verdict.is_correct()) {
+            return Some(Verdict::Incorrect);
+        }
+
+        let answer: i64 = answer.parse().ok()?;
+        previous.iter().find_map(|s| {
+            let bound: i64 = s.answer.parse().ok()?;
+            match s.verdict {
+                Verdict::TooHigh if answer >= bound => Some(Verdict::TooHigh),
+                Verdict::TooLow if answer <= bound => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::core::{puzzle::PuzzlePart, util::test_dir};
+
+    use super::{Submission, SubmissionLog, Verdict};
+
+    fn submission(part: PuzzlePart, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            year: 2020,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn it_reads_verdicts_from_responses() {
+        assert_eq!(
+            Verdict::from_response("<p>That's the right answer!</p>"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response("<p>That's not the right answer; your answer is too high.</p>"),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_response("<p>That's not the right answer.</p>"),
+            Some(Verdict::Incorrect)
+        );
+        assert_eq!(
+            Verdict::from_response("<p>You gave an answer too recently</p>"),
+            None
+        );
+    }
+
+    #[test]
+    fn it_persists_submissions() {
+        let dir = test_dir("submissions-persist");
+        let path = dir.join("submissions.tsv");
+
+        let mut log = SubmissionLog::open(&path).unwrap();
+        log.record(submission(PuzzlePart::One, "42", Verdict::TooLow))
+            .unwrap();
+        log.record(submission(PuzzlePart::One, "with\ttab", Verdict::Incorrect))
+            .unwrap();
+
+        let log = SubmissionLog::open(&path).unwrap();
+        assert_eq!(
+            log.submissions(),
+            &[
+                submission(PuzzlePart::One, "42", Verdict::TooLow),
+                submission(PuzzlePart::One, "with\ttab", Verdict::Incorrect),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_escapes_answers_and_skips_malformed_lines() {
+        let dir = test_dir("submissions-malformed");
+        let path = dir.join("submissions.tsv");
+        let multi_line = submission(PuzzlePart::One, "two\nlines", Verdict::Incorrect);
+        let backslash = submission(PuzzlePart::Two, "back\\slash", Verdict::Correct);
+
+        let mut log = SubmissionLog::open(&path).unwrap();
+        log.record(multi_line.clone()).unwrap();
+
+        let mut contents = fs::read_to_string(&path).unwrap();
+        contents += "2020\t1\tone\n\n2020\t1\t2\tcorrect\tunknown \\q escape\n";
+        fs::write(&path, contents).unwrap();
+
+        let mut log = SubmissionLog::open(&path).unwrap();
+        log.record(backslash.clone()).unwrap();
+
+        let log = SubmissionLog::open(&path).unwrap();
+        assert_eq!(log.submissions(), &[multi_line, backslash]);
+        assert_eq!(log.malformed_lines(), &[2, 4]);
+    }
+
+    #[test]
+    fn it_knows_verdicts_without_submitting() {
+        let dir = test_dir("submissions-known");
+
+        let mut log = SubmissionLog::open(&dir.join("submissions.tsv")).unwrap();
+        log.record(submission(PuzzlePart::One, "100", Verdict::TooHigh))
+            .unwrap();
+        log.record(submission(PuzzlePart::One, "10", Verdict::TooLow))
+            .unwrap();
+        log.record(submission(PuzzlePart::Two, "7", Verdict::Correct))
+            .unwrap();
+
+        let known = |part, answer| log.known_verdict(2020, 1, part, answer);
+        assert_eq!(known(PuzzlePart::One, "100"), Some(Verdict::TooHigh));
+        assert_eq!(known(PuzzlePart::One, "150"), Some(Verdict::TooHigh));
+        assert_eq!(known(PuzzlePart::One, "5"), Some(Verdict::TooLow));
+        assert_eq!(known(PuzzlePart::One, "50"), None);
+        assert_eq!(known(PuzzlePart::Two, "7"), Some(Verdict::Correct));
+        assert_eq!(known(PuzzlePart::Two, "8"), Some(Verdict::Incorrect));
+        assert_eq!(log.known_verdict(2020, 2, PuzzlePart::One, "100"), None);
+    }
+}
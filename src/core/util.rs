@@ -1,4 +1,12 @@
-use std::{collections::HashMap, ops::Range};
+use std::{collections::HashMap, fmt, ops::Range};
+
+#[cfg(test)]
+use std::{
+    fs,
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 pub fn last_n(n: usize, max: usize) -> Range<usize> {
     if n <= max {
@@ -31,6 +39,78 @@ where
     entries.into_iter().collect()
 }
 
+/// Displays a string as a quoted and escaped JSON string
+pub struct JsonString<'a>(pub &'a str);
+
+impl fmt::Display for JsonString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"")?;
+        for c in self.0.chars() {
+            match c {
+                '"' => write!(f, "\\\"")?,
+                '\\' => write!(f, "\\\\")?,
+                '\n' => write!(f, "\\n")?,
+                '\r' => write!(f, "\\r")?,
+                '\t' => write!(f, "\\t")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        write!(f, "\"")
+    }
+}
+
+/// Escape the characters that would break a field of a tab-separated line
+pub fn escape_field(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for ch in field.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Undo `escape_field`, or `None` if the field has an unknown escape sequence
+pub fn unescape_field(field: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            unescaped.push(ch);
+            continue;
+        }
+        let ch = match chars.next()? {
+            '\\' => '\\',
+            't' => '\t',
+            'n' => '\n',
+            'r' => '\r',
+            _ => return None,
+        };
+        unescaped.push(ch);
+    }
+    Some(unescaped)
+}
+
+/// Make an empty directory for a test to write files into. The name includes
+/// the process id and a counter, so that tests never share a directory, even
+/// across test runs at the same time.
+#[cfg(test)]
+pub fn test_dir(name: &str) -> PathBuf {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    let count = COUNT.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("advent-{}-{}-{}", name, process::id(), count));
+    // Clear out anything left behind by an earlier process with the same id
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
 #[cfg(test)]
 mod tests {
     use super::split_groups;
@@ -1,32 +1,173 @@
-use super::grid::Grid;
+use std::marker::PhantomData;
 
-/// A grid that wraps in the positive X direction
+use super::{
+    geom::{Point, Vec2},
+    grid::Grid,
+};
+
+/// Decides which edges of a grid wrap around to the opposite edge
+pub trait Topology {
+    const WRAPS_X: bool;
+    const WRAPS_Y: bool;
+}
+
+/// Nothing wraps, so points past an edge are off the grid
+#[derive(Debug, Clone)]
+pub struct NoWrap {}
+
+impl Topology for NoWrap {
+    const WRAPS_X: bool = false;
+    const WRAPS_Y: bool = false;
+}
+
+/// The left and right edges are joined
+#[derive(Debug, Clone)]
+pub struct WrapHorizontal {}
+
+impl Topology for WrapHorizontal {
+    const WRAPS_X: bool = true;
+    const WRAPS_Y: bool = false;
+}
+
+/// The top and bottom edges are joined
+#[derive(Debug, Clone)]
+pub struct WrapVertical {}
+
+impl Topology for WrapVertical {
+    const WRAPS_X: bool = false;
+    const WRAPS_Y: bool = true;
+}
+
+/// Both pairs of opposite edges are joined
+#[derive(Debug, Clone)]
+pub struct Toroidal {}
+
+impl Topology for Toroidal {
+    const WRAPS_X: bool = true;
+    const WRAPS_Y: bool = true;
+}
+
+/// A grid addressed with signed points, whose edges wrap according to a
+/// topology
 #[derive(Debug, Clone)]
-pub struct WrapGrid<T: Clone> {
+pub struct WrapGrid<T: Clone, P: Topology = WrapHorizontal> {
     grid: Grid<T>,
+    _phantom: PhantomData<P>,
 }
 
-impl<T> WrapGrid<T>
+impl<T, P> WrapGrid<T, P>
 where
     T: Clone,
+    P: Topology,
 {
     pub fn new(grid: Grid<T>) -> Self {
-        Self { grid }
+        Self {
+            grid,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.width()
     }
 
     pub fn height(&self) -> usize {
         self.grid.height()
     }
 
-    pub fn get(&self, mut x: usize, y: usize) -> Option<&T> {
-        if y >= self.grid.height() {
-            return None;
-        }
+    /// Map a point onto the grid, or `None` if it falls past an edge that
+    /// does not wrap
+    pub fn resolve(&self, point: Point<isize>) -> Option<Point<usize>> {
+        let x = wrap(point.x, self.grid.width(), P::WRAPS_X)?;
+        let y = wrap(point.y, self.grid.height(), P::WRAPS_Y)?;
+        Some(Point::new(x, y))
+    }
 
-        if x >= self.grid.width() {
-            x %= self.grid.width();
-        }
+    pub fn get(&self, point: Point<isize>) -> Option<&T> {
+        let point = self.resolve(point)?;
+        self.grid.get(point.x, point.y)
+    }
+
+    pub fn get_mut(&mut self, point: Point<isize>) -> Option<&mut T> {
+        let point = self.resolve(point)?;
+        self.grid.get_mut(point.x, point.y)
+    }
+
+    /// Get the point one offset away, or `None` if it is off the grid
+    pub fn step(&self, point: Point<isize>, offset: Vec2<isize>) -> Option<Point<usize>> {
+        self.resolve(point + offset)
+    }
+}
+
+fn wrap(value: isize, len: usize, wraps: bool) -> Option<usize> {
+    if len == 0 {
+        None
+    } else if wraps {
+        Some(value.rem_euclid(len as isize) as usize)
+    } else if value >= 0 && (value as usize) < len {
+        Some(value as usize)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::core::{
+        geom::{Direction, Point},
+        grid::Grid,
+    };
+
+    use super::{NoWrap, Toroidal, WrapGrid, WrapHorizontal, WrapVertical};
+
+    fn grid() -> Grid<char> {
+        Grid::try_from(vec!["abc", "def"]).unwrap()
+    }
+
+    #[test]
+    fn it_wraps_each_topology() {
+        let bounded = WrapGrid::<_, NoWrap>::new(grid());
+        let horizontal = WrapGrid::<_, WrapHorizontal>::new(grid());
+        let vertical = WrapGrid::<_, WrapVertical>::new(grid());
+        let torus = WrapGrid::<_, Toroidal>::new(grid());
+
+        let left = Point::new(-1, 1);
+        assert_eq!(bounded.get(left), None);
+        assert_eq!(horizontal.get(left), Some(&'f'));
+        assert_eq!(vertical.get(left), None);
+        assert_eq!(torus.get(left), Some(&'f'));
+
+        let below = Point::new(4, 2);
+        assert_eq!(bounded.get(below), None);
+        assert_eq!(horizontal.get(below), None);
+        assert_eq!(vertical.get(below), None);
+        assert_eq!(torus.get(below), Some(&'b'));
+
+        assert_eq!(vertical.get(Point::new(0, -3)), Some(&'d'));
+        assert_eq!(bounded.get(Point::new(2, 1)), Some(&'f'));
+    }
+
+    #[test]
+    fn it_steps_across_edges() {
+        let mut torus = WrapGrid::<_, Toroidal>::new(grid());
+
+        let corner = Point::new(0, 0);
+        assert_eq!(
+            torus.step(corner, Direction::Up.offset()),
+            Some(Point::new(0, 1))
+        );
+        assert_eq!(
+            torus.step(corner, Direction::Left.offset()),
+            Some(Point::new(2, 0))
+        );
 
-        self.grid.get(x, y)
+        *torus.get_mut(Point::new(-1, -1)).unwrap() = 'x';
+        assert_eq!(torus.grid().get(2, 1), Some(&'x'));
     }
 }
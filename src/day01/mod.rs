@@ -1,16 +1,15 @@
 pub mod part1;
 pub mod part2;
 
-use std::{cell::RefCell, collections::HashSet, rc::Rc};
+use std::collections::HashSet;
 
-use cursive::Cursive;
 use part1::Solve2Sum;
 use part2::Solve3Sum;
 
 use crate::core::{
-    fs::parse_lines,
-    puzzle::{Puzzle, PuzzlePart},
-    solver::solve,
+    fs::parse_str_lines,
+    puzzle::{Puzzle, PuzzlePart, SolverResult},
+    solver::boxed,
 };
 
 #[derive(Debug)]
@@ -27,19 +26,28 @@ impl Puzzle for Day01 {
         "Report Repair".to_string()
     }
 
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        1
+    }
+
+    fn get_input_path(&self) -> String {
+        "day01/expense_report.txt".to_string()
+    }
+
     fn is_implemented(&self, _part: PuzzlePart) -> bool {
         true
     }
 
-    fn run(&self, part: PuzzlePart, c: Rc<RefCell<Cursive>>) {
-        let elements: Vec<i64> =
-            parse_lines("input/day01/expense_report.txt").expect("Could not load puzzle input");
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let elements: Vec<i64> = parse_str_lines(input)?;
         if part == PuzzlePart::One {
-            let solver = Solve2Sum::new(elements, 2020);
-            solve(solver, c);
+            Ok(boxed(Solve2Sum::new(elements, 2020)))
         } else {
-            let solver = Solve3Sum::new(elements, 2020);
-            solve(solver, c);
+            Ok(boxed(Solve3Sum::new(elements, 2020)))
         }
     }
 }
@@ -68,7 +76,9 @@ pub fn find_3sum(xs: &HashSet<i32>, sum: i32) -> Option<(i32, i32, i32)> {
 
 #[cfg(test)]
 mod tests {
-    use super::{find_2sum, find_3sum};
+    use super::{find_2sum, find_3sum, Day01};
+    use crate::core::puzzle::{Puzzle, PuzzlePart};
+    use crate::core::solver::Solver;
     use std::collections::HashSet;
 
     fn get_test_set() -> HashSet<i32> {
@@ -98,4 +108,18 @@ mod tests {
         let pair = find_2sum(&xs, 2021);
         assert_eq!(pair, None);
     }
+
+    #[test]
+    fn it_solves_both_parts_from_input() {
+        let input = "1721\n979\n366\n299\n675\n1456\n";
+        let day = Day01::new();
+        assert_eq!(
+            day.solver(PuzzlePart::One, input).unwrap().solve(),
+            Some("514579".to_string())
+        );
+        assert_eq!(
+            day.solver(PuzzlePart::Two, input).unwrap().solve(),
+            Some("241861950".to_string())
+        );
+    }
 }
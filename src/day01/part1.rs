@@ -2,7 +2,10 @@ use std::collections::HashSet;
 
 use cursive::Printer;
 
-use crate::core::{solver::Solver, util::last_n};
+use crate::core::{
+    solver::{snapshot_of, Solver},
+    util::last_n,
+};
 
 #[derive(Debug, Clone)]
 pub struct Solve2Sum {
@@ -60,8 +63,22 @@ impl Solver<i64> for Solve2Sum {
         }
     }
 
+    fn steps_taken(&self) -> Option<usize> {
+        Some(self.i + self.found.is_some() as usize)
+    }
+
+    fn estimated_steps(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+
+    fn snapshot(&self) -> Option<Box<dyn Solver<i64>>> {
+        snapshot_of(self)
+    }
+
     fn draw(&self, printer: &Printer) {
-        let range = last_n(self.history.len(), printer.size.y);
+        // Leave room for the solution once it is found
+        let rows = printer.size.y.saturating_sub(self.found.is_some() as usize);
+        let range = last_n(self.history.len(), rows);
         let mut i = 0;
         for (x, y) in &self.history[range] {
             let line = format_line(*x, *y, x + y, false);
@@ -80,3 +97,38 @@ fn format_line(x: i64, y: i64, sum: i64, found: bool) -> String {
     let found_text = if found { "Found!" } else { "Not found" };
     format!("Input {1}: {0} - {1} = {2} ... {3}", sum, x, y, found_text)
 }
+
+#[cfg(test)]
+mod tests {
+    use cursive::Vec2;
+
+    use crate::core::{offscreen::render_solver, solver::Solver};
+
+    use super::Solve2Sum;
+
+    #[test]
+    fn it_draws_each_attempt() {
+        let mut solver = Solve2Sum::new(vec![979, 366, 1721, 299, 675, 1456], 2020);
+        solver.step();
+        solver.step();
+
+        let frame = render_solver(&solver, Vec2::new(48, 2));
+        assert_eq!(
+            frame.lines(),
+            vec![
+                "Input 979: 2020 - 979 = 1041 ... Not found",
+                "Input 366: 2020 - 366 = 1654 ... Not found",
+            ]
+        );
+
+        solver.solve();
+        let frame = render_solver(&solver, Vec2::new(48, 2));
+        assert_eq!(
+            frame.lines(),
+            vec![
+                "Input 366: 2020 - 366 = 1654 ... Not found",
+                "Input 1721: 2020 - 1721 = 299 ... Found!",
+            ]
+        );
+    }
+}
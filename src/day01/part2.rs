@@ -2,7 +2,10 @@ use std::collections::HashSet;
 
 use cursive::Printer;
 
-use crate::core::{solver::Solver, util::last_n};
+use crate::core::{
+    solver::{snapshot_of, Solver},
+    util::last_n,
+};
 
 #[derive(Debug, Clone)]
 pub struct Solve3Sum {
@@ -55,6 +58,18 @@ impl Solver<i64> for Solve3Sum {
         self.i += 1;
     }
 
+    fn steps_taken(&self) -> Option<usize> {
+        Some(self.i + self.found.is_some() as usize)
+    }
+
+    fn estimated_steps(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+
+    fn snapshot(&self) -> Option<Box<dyn Solver<i64>>> {
+        snapshot_of(self)
+    }
+
     fn draw(&self, printer: &Printer) {
         let mut i = 0;
         let range = last_n(self.i, printer.size.y - 1);
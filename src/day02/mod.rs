@@ -5,17 +5,17 @@ use cursive::{
     theme::{ColorStyle, Style},
     utils::span::SpannedStr,
     utils::span::SpannedString,
-    Cursive, Printer,
+    Printer,
 };
 use lazy_static::lazy_static;
 use regex::{Captures, Regex};
-use std::{cell::RefCell, collections::HashSet, marker::PhantomData, rc::Rc, str::FromStr};
+use std::{collections::HashSet, marker::PhantomData, str::FromStr};
 use thiserror::Error;
 
 use crate::core::{
-    fs::parse_lines,
-    puzzle::{Puzzle, PuzzlePart},
-    solver::solve,
+    fs::parse_str_lines,
+    puzzle::{Puzzle, PuzzlePart, SolverResult},
+    solver::boxed,
     solver::Solver,
     util::last_n,
 };
@@ -305,19 +305,28 @@ impl Puzzle for Day02 {
         "Password Philosophy".to_string()
     }
 
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        2
+    }
+
+    fn get_input_path(&self) -> String {
+        "day02/password_database.txt".to_string()
+    }
+
     fn is_implemented(&self, _part: PuzzlePart) -> bool {
         true
     }
 
-    fn run(&self, part: PuzzlePart, c: Rc<RefCell<Cursive>>) {
-        let passwords: Vec<PasswordEntry> =
-            parse_lines("input/day02/password_database.txt").expect("Could not load puzzle input");
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let passwords: Vec<PasswordEntry> = parse_str_lines(input)?;
         if part == PuzzlePart::One {
-            let solver = SolveDay02::<OldPolicy>::new(passwords);
-            solve(solver, c);
+            Ok(boxed(SolveDay02::<OldPolicy>::new(passwords)))
         } else {
-            let solver = SolveDay02::<NewPolicy>::new(passwords);
-            solve(solver, c);
+            Ok(boxed(SolveDay02::<NewPolicy>::new(passwords)))
         }
     }
 }
@@ -1,18 +1,14 @@
 pub mod part1;
 pub mod part2;
 
-use std::convert::TryFrom;
-use std::{cell::RefCell, rc::Rc};
-
-use cursive::Cursive;
 use part1::SolvePart1;
 use part2::SolvePart2;
 
 use crate::core::{
-    fs::parse_lines,
+    geom::Vec2,
     grid::Grid,
-    puzzle::{Puzzle, PuzzlePart},
-    solver::solve,
+    puzzle::{Puzzle, PuzzlePart, SolverResult},
+    solver::boxed,
 };
 
 #[derive(Debug)]
@@ -29,21 +25,69 @@ impl Puzzle for Day03 {
         "Toboggan Trajectory".to_string()
     }
 
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        3
+    }
+
+    fn get_input_path(&self) -> String {
+        "day03/map.txt".to_string()
+    }
+
     fn is_implemented(&self, _part: PuzzlePart) -> bool {
         true
     }
 
-    fn run(&self, part: PuzzlePart, c: Rc<RefCell<Cursive>>) {
-        let rows: Vec<String> =
-            parse_lines("input/day03/map.txt").expect("Could not load puzzle input");
-        let grid = Grid::try_from(rows).expect("Could not parse puzzle input");
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let grid: Grid<char> = input.parse()?;
         if part == PuzzlePart::One {
-            let solver = SolvePart1::new(grid, (3, 1));
-            solve(solver, c);
+            Ok(boxed(SolvePart1::new(grid, Vec2::new(3, 1))?))
         } else {
-            let strategies = vec![(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
-            let solver = SolvePart2::new(grid, strategies);
-            solve(solver, c);
+            let strategies = vec![
+                Vec2::new(1, 1),
+                Vec2::new(3, 1),
+                Vec2::new(5, 1),
+                Vec2::new(7, 1),
+                Vec2::new(1, 2),
+            ];
+            Ok(boxed(SolvePart2::new(grid, strategies)?))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::puzzle::{Puzzle, PuzzlePart};
+    use crate::core::solver::Solver;
+
+    use super::Day03;
+
+    const EXAMPLE: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+    #[test]
+    fn it_solves_both_parts_from_input() {
+        let day = Day03::new();
+        assert_eq!(
+            day.solver(PuzzlePart::One, EXAMPLE).unwrap().solve(),
+            Some("7".to_string())
+        );
+        assert_eq!(
+            day.solver(PuzzlePart::Two, EXAMPLE).unwrap().solve(),
+            Some("336".to_string())
+        );
+    }
+}
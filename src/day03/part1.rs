@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::Range};
 
 use cursive::{
     theme::{ColorStyle, Style},
@@ -7,31 +7,66 @@ use cursive::{
     Printer,
 };
 
-use crate::core::{grid::Grid, solver::Solver, util::last_n, wrap_grid::WrapGrid};
+use crate::core::{
+    geom::{Point, Vec2},
+    grid::Grid,
+    puzzle::PuzzleError,
+    solver::Solver,
+    util::last_n,
+    wrap_grid::WrapGrid,
+};
 
 pub struct SolvePart1 {
     grid: WrapGrid<char>,
-    strategy: (usize, usize),
-    position: (usize, usize),
+    strategy: Vec2<isize>,
+    position: Point<isize>,
     count: i64,
-    history: HashMap<usize, (usize, char)>,
+    /// The column and marker of each visited row
+    history: HashMap<usize, (isize, char)>,
 }
 
 impl SolvePart1 {
-    pub fn new(grid: Grid<char>, strategy: (usize, usize)) -> Self {
-        Self {
+    /// Create a solver for the given slope, which must move down so that the
+    /// toboggan crosses the map from the top to the bottom
+    pub fn new(grid: Grid<char>, strategy: Vec2<isize>) -> Result<Self, PuzzleError> {
+        if strategy.y == 0 {
+            return Err(PuzzleError::Unsolvable(format!(
+                "Slope {} never leaves the map because it does not move up or down",
+                strategy
+            )));
+        }
+        if strategy.y < 0 {
+            return Err(PuzzleError::Unsolvable(format!(
+                "Slope {} leaves the map at the top instead of crossing it",
+                strategy
+            )));
+        }
+
+        Ok(Self {
             grid: WrapGrid::new(grid),
             strategy,
-            position: (0, 0),
+            position: Point::default(),
             count: 0,
             history: HashMap::new(),
+        })
+    }
+
+    /// The columns to draw: the ones already passed, up to the width
+    /// available, nearest the current position
+    fn columns(&self, width: usize) -> Range<isize> {
+        let x = self.position.x;
+        let width = width as isize;
+        if self.strategy.x >= 0 {
+            (x - width).max(0)..x
+        } else {
+            x + 1..(x + 1 + width).min(1)
         }
     }
 }
 
 impl Solver<i64> for SolvePart1 {
     fn is_done(&self) -> bool {
-        self.position.1 >= self.grid.height()
+        self.grid.resolve(self.position).is_none()
     }
 
     fn solution(&self) -> Option<i64> {
@@ -43,9 +78,7 @@ impl Solver<i64> for SolvePart1 {
             return;
         }
 
-        let mut x = self.position.0;
-        let mut y = self.position.1;
-        let current = self.grid.get(x, y).unwrap();
+        let current = self.grid.get(self.position).unwrap();
 
         let marker: char;
         if current == &'#' {
@@ -54,26 +87,31 @@ impl Solver<i64> for SolvePart1 {
         } else {
             marker = 'O';
         }
-        self.history.insert(y, (x, marker));
-
-        x += self.strategy.0;
-        y += self.strategy.1;
+        let row = self.position.y as usize;
+        self.history.insert(row, (self.position.x, marker));
 
-        self.position = (x, y);
+        self.position += self.strategy;
     }
 
     fn draw(&self, printer: &Printer) {
-        let y_range = last_n(self.position.1.min(self.grid.height()), printer.size.y);
-        for (i, y) in y_range.enumerate() {
-            let x_range = last_n(self.position.0, printer.size.x);
-            let text: String = x_range.map(|x| self.grid.get(x, y).unwrap()).collect();
+        let bottom = (self.position.y as usize).min(self.grid.height());
+        let columns = self.columns(printer.size.x);
+        for (i, y) in last_n(bottom, printer.size.y).enumerate() {
+            let text: String = columns
+                .clone()
+                .map(|x| self.grid.get(Point::new(x, y as isize)).unwrap())
+                .collect();
+            let marker = self
+                .history
+                .get(&y)
+                .filter(|(x, _)| columns.contains(x))
+                .map(|&(x, c)| ((x - columns.start) as usize, c));
             let mut styled: SpannedString<Style>;
-            if let Some((x, c)) = self.history.get(&y) {
-                let x = x % printer.size.x;
+            if let Some((x, c)) = marker {
                 let (plain1, rest) = text.split_at(x);
                 let (_, plain2) = rest.split_at(1);
                 styled = SpannedString::<Style>::plain(plain1);
-                let color = if c == &'X' {
+                let color = if c == 'X' {
                     ColorStyle::secondary()
                 } else {
                     ColorStyle::tertiary()
@@ -86,13 +124,25 @@ impl Solver<i64> for SolvePart1 {
             printer.print_styled((0, i), SpannedStr::from(&styled));
         }
     }
+
+    fn steps_taken(&self) -> Option<usize> {
+        Some(self.history.len())
+    }
+
+    fn estimated_steps(&self) -> Option<usize> {
+        Some(self.grid.height().div_ceil(self.strategy.y as usize))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
 
-    use crate::core::{grid::Grid, solver::Solver};
+    use cursive::Vec2;
+
+    use crate::core::{
+        geom, grid::Grid, offscreen::render_solver, puzzle::PuzzleError, solver::Solver,
+    };
 
     use super::SolvePart1;
 
@@ -113,9 +163,61 @@ mod tests {
         ];
         let grid = Grid::try_from(input).unwrap();
 
-        let mut solver = SolvePart1::new(grid, (3, 1));
+        let mut solver = SolvePart1::new(grid, geom::Vec2::new(3, 1)).unwrap();
         let solution = solver.solve();
 
         assert_eq!(solution, Some(7));
+        assert_eq!(solver.steps_taken(), Some(11));
+        assert_eq!(solver.estimated_steps(), Some(11));
+    }
+
+    #[test]
+    fn it_estimates_steps_for_steep_slopes() {
+        let grid = Grid::try_from(vec!["..", "#.", "..", "#.", ".."]).unwrap();
+
+        let mut solver = SolvePart1::new(grid, geom::Vec2::new(1, 2)).unwrap();
+        assert_eq!(solver.estimated_steps(), Some(3));
+
+        solver.step();
+        assert_eq!(solver.steps_taken(), Some(1));
+        assert_eq!(solver.solve(), Some(0));
+        assert_eq!(solver.steps_taken(), Some(3));
+    }
+
+    #[test]
+    fn it_draws_the_path_with_hits_highlighted() {
+        let grid = Grid::try_from(vec!["#..", ".#.", "..."]).unwrap();
+        let mut solver = SolvePart1::new(grid, geom::Vec2::new(1, 1)).unwrap();
+        solver.solve();
+
+        let frame = render_solver(&solver, Vec2::new(3, 3));
+        assert_eq!(frame.lines(), vec!["X..", ".X.", "..O"]);
+        assert_eq!(frame.style_lines(), vec!["s", " s", "  t"]);
+    }
+
+    #[test]
+    fn it_wraps_when_sloping_to_the_left() {
+        let grid = Grid::try_from(vec!["#..", "..#", ".#."]).unwrap();
+        let mut solver = SolvePart1::new(grid, geom::Vec2::new(-1, 1)).unwrap();
+        assert_eq!(solver.solve(), Some(3));
+
+        let frame = render_solver(&solver, Vec2::new(3, 3));
+        assert_eq!(frame.lines(), vec!["..X", ".X.", "X.."]);
+        assert_eq!(frame.style_lines(), vec!["  s", " s", "s"]);
+    }
+
+    #[test]
+    fn it_rejects_slopes_that_never_leave_the_map() {
+        let grid = Grid::try_from(vec!["..", "#."]).unwrap();
+        assert!(SolvePart1::new(grid, geom::Vec2::new(3, 0)).is_err());
+    }
+
+    #[test]
+    fn it_rejects_upward_slopes() {
+        let grid = Grid::try_from(vec!["..", "#."]).unwrap();
+        assert!(matches!(
+            SolvePart1::new(grid, geom::Vec2::new(1, -1)),
+            Err(PuzzleError::Unsolvable(_))
+        ));
     }
 }
@@ -1,31 +1,32 @@
-use crate::core::{grid::Grid, solver::Solver};
+use crate::core::{geom::Vec2, grid::Grid, puzzle::PuzzleError, solver::Solver};
 use cursive::Printer;
 
 use super::part1::SolvePart1;
 
 pub struct SolvePart2 {
-    grid: Grid<char>,
-    solver: Option<SolvePart1>,
+    solvers: Vec<SolvePart1>,
     i: usize,
     counts: Vec<i64>,
-    strategies: Vec<(usize, usize)>,
 }
 
 impl SolvePart2 {
-    pub fn new(grid: Grid<char>, strategies: Vec<(usize, usize)>) -> Self {
-        Self {
-            grid,
-            solver: None,
+    pub fn new(grid: Grid<char>, strategies: Vec<Vec2<isize>>) -> Result<Self, PuzzleError> {
+        let solvers = strategies
+            .into_iter()
+            .map(|strategy| SolvePart1::new(grid.clone(), strategy))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            solvers,
             i: 0,
             counts: Vec::new(),
-            strategies,
-        }
+        })
     }
 }
 
 impl Solver<i64> for SolvePart2 {
     fn is_done(&self) -> bool {
-        self.counts.len() == self.strategies.len()
+        self.counts.len() == self.solvers.len()
     }
 
     fn solution(&self) -> Option<i64> {
@@ -33,17 +34,14 @@ impl Solver<i64> for SolvePart2 {
     }
 
     fn step(&mut self) {
-        let strategy = self.strategies[self.i];
-        let mut solver = SolvePart1::new(self.grid.clone(), strategy);
-        if let Some(count) = solver.solve() {
+        if let Some(count) = self.solvers[self.i].solve() {
             self.counts.push(count);
         }
         self.i += 1;
-        self.solver = Some(solver);
     }
 
     fn draw(&self, printer: &Printer) {
-        if let Some(solver) = &self.solver {
+        if let Some(solver) = self.solvers[..self.i].last() {
             solver.draw(printer);
         }
     }
@@ -51,6 +49,7 @@ impl Solver<i64> for SolvePart2 {
 
 #[cfg(test)]
 mod tests {
+    use crate::core::geom::Vec2;
     use crate::core::grid::Grid;
     use crate::core::solver::Solver;
     use std::convert::TryFrom;
@@ -73,9 +72,15 @@ mod tests {
             ".#..#...#.#",
         ];
         let grid = Grid::try_from(input).unwrap();
-        let strategies = vec![(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+        let strategies = vec![
+            Vec2::new(1, 1),
+            Vec2::new(3, 1),
+            Vec2::new(5, 1),
+            Vec2::new(7, 1),
+            Vec2::new(1, 2),
+        ];
 
-        let mut solver = SolvePart2::new(grid, strategies);
+        let mut solver = SolvePart2::new(grid, strategies).unwrap();
         let solution = solver.solve();
 
         assert_eq!(solution, Some(336));
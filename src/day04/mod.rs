@@ -1,2 +1,52 @@
 pub mod passport;
 pub mod policy;
+pub mod validator;
+
+use passport::Passport;
+use policy::{RelaxedPolicy, StrictPolicy};
+use validator::PassportValidator;
+
+use crate::core::{
+    puzzle::{Puzzle, PuzzlePart, SolverResult},
+    solver::boxed,
+};
+
+#[derive(Debug, Default)]
+pub struct Day04 {}
+
+impl Day04 {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Puzzle for Day04 {
+    fn get_title(&self) -> String {
+        "Passport Processing".to_string()
+    }
+
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        4
+    }
+
+    fn get_input_path(&self) -> String {
+        "day04/passports.txt".to_string()
+    }
+
+    fn is_implemented(&self, _part: PuzzlePart) -> bool {
+        true
+    }
+
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let passports = Passport::parse_batch(input);
+        if part == PuzzlePart::One {
+            Ok(boxed(PassportValidator::<RelaxedPolicy>::new(passports)))
+        } else {
+            Ok(boxed(PassportValidator::<StrictPolicy>::new(passports)))
+        }
+    }
+}
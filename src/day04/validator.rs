@@ -0,0 +1,135 @@
+use std::marker::PhantomData;
+
+use cursive::{
+    theme::{ColorStyle, Style},
+    utils::span::SpannedStr,
+    utils::span::SpannedString,
+    Printer,
+};
+
+use crate::core::{solver::Solver, util::last_n};
+
+use super::{passport::Passport, policy::ValidationPolicy};
+
+pub struct PassportValidator<P: ValidationPolicy> {
+    passports: Vec<Passport>,
+    i: usize,
+    valid_count: usize,
+    history: Vec<bool>,
+    _phantom: PhantomData<P>,
+}
+
+impl<P> PassportValidator<P>
+where
+    P: ValidationPolicy,
+{
+    pub fn new(passports: Vec<Passport>) -> Self {
+        Self {
+            passports,
+            i: 0,
+            valid_count: 0,
+            history: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P> Solver<usize> for PassportValidator<P>
+where
+    P: ValidationPolicy,
+{
+    fn is_done(&self) -> bool {
+        self.i == self.passports.len()
+    }
+
+    fn solution(&self) -> Option<usize> {
+        self.with_done_some(self.valid_count)
+    }
+
+    fn step(&mut self) {
+        if self.is_done() {
+            return;
+        }
+
+        let is_valid = self.passports[self.i].is_valid::<P>();
+        if is_valid {
+            self.valid_count += 1;
+        }
+        self.history.push(is_valid);
+
+        self.i += 1;
+    }
+
+    fn draw(&self, printer: &Printer) {
+        let range = last_n(self.history.len(), printer.size.y);
+        for (i, j) in range.enumerate() {
+            let styled = format_passport(&self.passports[j], self.history[j]);
+            printer.print_styled((0, i), SpannedStr::from(&styled));
+        }
+    }
+}
+
+fn format_passport(passport: &Passport, is_valid: bool) -> SpannedString<Style> {
+    let fields = [
+        ("byr", &passport.birth_year),
+        ("iyr", &passport.issue_year),
+        ("eyr", &passport.expiration_year),
+        ("hgt", &passport.height),
+        ("hcl", &passport.hair_color),
+        ("ecl", &passport.eye_color),
+        ("pid", &passport.passport_id),
+        ("cid", &passport.country_id),
+    ];
+
+    let mut styled = SpannedString::new();
+    for (key, value) in fields.iter() {
+        let value = value.as_deref().unwrap_or("-");
+        styled.append(format!("{}:{:<10} ", key, value));
+    }
+
+    styled.append("... ");
+
+    let (text, color) = if is_valid {
+        ("Valid", ColorStyle::secondary())
+    } else {
+        ("Invalid", ColorStyle::tertiary())
+    };
+    styled.append(SpannedString::styled(text, Style::from(color)));
+
+    styled
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::solver::Solver;
+    use crate::day04::{
+        passport::Passport,
+        policy::{RelaxedPolicy, StrictPolicy},
+    };
+
+    use super::PassportValidator;
+
+    const BATCH: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in";
+
+    #[test]
+    fn it_solves_the_example() {
+        let passports = Passport::parse_batch(BATCH);
+        let mut solver = PassportValidator::<RelaxedPolicy>::new(passports.clone());
+        assert_eq!(solver.solve(), Some(2));
+
+        let mut solver = PassportValidator::<StrictPolicy>::new(passports);
+        assert_eq!(solver.solve(), Some(2));
+    }
+}
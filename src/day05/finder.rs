@@ -1,5 +1,14 @@
 use std::collections::HashSet;
 
+use cursive::{
+    theme::{ColorStyle, Style},
+    utils::span::SpannedStr,
+    utils::span::SpannedString,
+    Printer,
+};
+
+use crate::core::solver::Solver;
+
 use super::resolver::{seat_id, Resolver, NUM_COLS, NUM_ROWS};
 
 pub struct SeatFinder {
@@ -57,3 +66,36 @@ impl SeatFinder {
         self.found.unwrap()
     }
 }
+
+impl Solver<i64> for SeatFinder {
+    fn is_done(&self) -> bool {
+        self.is_found()
+    }
+
+    fn solution(&self) -> Option<i64> {
+        self.found
+    }
+
+    fn step(&mut self) {
+        SeatFinder::step(self);
+    }
+
+    /// Draw the seating chart with one line per column, where '#' marks a
+    /// seat that has been claimed by a boarding pass
+    fn draw(&self, printer: &Printer) {
+        for col in 0..NUM_COLS {
+            let mut styled = SpannedString::<Style>::new();
+            for row in 0..NUM_ROWS {
+                let id = seat_id(row, col);
+                if self.found == Some(id) {
+                    styled.append_styled("X", Style::from(ColorStyle::secondary()));
+                } else if self.seats.contains(&id) {
+                    styled.append_plain(".");
+                } else {
+                    styled.append_plain("#");
+                }
+            }
+            printer.print_styled((0, col as usize), SpannedStr::from(&styled));
+        }
+    }
+}
@@ -1,2 +1,51 @@
 pub mod finder;
 pub mod resolver;
+pub mod scanner;
+
+use finder::SeatFinder;
+use scanner::SeatScanner;
+
+use crate::core::{
+    puzzle::{Puzzle, PuzzlePart, SolverResult},
+    solver::boxed,
+};
+
+#[derive(Debug, Default)]
+pub struct Day05 {}
+
+impl Day05 {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Puzzle for Day05 {
+    fn get_title(&self) -> String {
+        "Binary Boarding".to_string()
+    }
+
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        5
+    }
+
+    fn get_input_path(&self) -> String {
+        "day05/boarding_passes.txt".to_string()
+    }
+
+    fn is_implemented(&self, _part: PuzzlePart) -> bool {
+        true
+    }
+
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let passes = input.lines().map(|line| line.to_string()).collect();
+        if part == PuzzlePart::One {
+            Ok(boxed(SeatScanner::new(passes)))
+        } else {
+            Ok(boxed(SeatFinder::new(passes)))
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use cursive::{
+    theme::{ColorStyle, Style},
+    utils::span::SpannedStr,
+    utils::span::SpannedString,
+    Printer,
+};
+
+use crate::core::{solver::Solver, util::last_n};
+
+use super::resolver::Resolver;
+
+/// Resolves each boarding pass in turn, keeping track of the highest seat ID
+pub struct SeatScanner {
+    passes: Vec<String>,
+    seat_ids: Vec<i64>,
+    highest: Option<usize>,
+}
+
+impl SeatScanner {
+    pub fn new(passes: Vec<String>) -> Self {
+        Self {
+            passes,
+            seat_ids: Vec::new(),
+            highest: None,
+        }
+    }
+}
+
+impl Solver<i64> for SeatScanner {
+    fn is_done(&self) -> bool {
+        self.seat_ids.len() == self.passes.len()
+    }
+
+    fn solution(&self) -> Option<i64> {
+        self.highest
+            .and_then(|i| self.with_done_some(self.seat_ids[i]))
+    }
+
+    fn step(&mut self) {
+        if self.is_done() {
+            return;
+        }
+
+        let i = self.seat_ids.len();
+        let seat_id = Resolver::new(&self.passes[i]).resolve();
+        self.seat_ids.push(seat_id);
+
+        match self.highest {
+            Some(j) if self.seat_ids[j] >= seat_id => (),
+            _ => self.highest = Some(i),
+        }
+    }
+
+    fn draw(&self, printer: &Printer) {
+        let range = last_n(self.seat_ids.len(), printer.size.y);
+        for (y, i) in range.enumerate() {
+            let line = format!("{} ... {}", self.passes[i], self.seat_ids[i]);
+            if self.highest == Some(i) {
+                let styled = SpannedString::styled(line, Style::from(ColorStyle::secondary()));
+                printer.print_styled((0, y), SpannedStr::from(&styled));
+            } else {
+                printer.print((0, y), &line);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::solver::Solver;
+
+    use super::SeatScanner;
+
+    #[test]
+    fn it_finds_the_highest_seat_id() {
+        let passes = vec!["FBFBBFFRLR", "BFFFBBFRRR", "FFFBBBFRRR", "BBFFBBFRLL"];
+        let passes = passes.into_iter().map(|s| s.to_string()).collect();
+        let mut solver = SeatScanner::new(passes);
+        assert_eq!(solver.solve(), Some(820));
+    }
+}
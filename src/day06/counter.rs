@@ -0,0 +1,106 @@
+use std::marker::PhantomData;
+
+use cursive::Printer;
+
+use crate::core::{solver::Solver, util::last_n};
+
+use super::group::{CustomsPolicy, Group};
+
+/// Counts the answers of each group in turn according to a customs policy
+pub struct GroupCounter<P: CustomsPolicy> {
+    groups: Vec<Group>,
+    counts: Vec<usize>,
+    sum: usize,
+    _phantom: PhantomData<P>,
+}
+
+impl<P> GroupCounter<P>
+where
+    P: CustomsPolicy,
+{
+    pub fn new(groups: Vec<Group>) -> Self {
+        Self {
+            groups,
+            counts: Vec::new(),
+            sum: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P> Solver<usize> for GroupCounter<P>
+where
+    P: CustomsPolicy,
+{
+    fn is_done(&self) -> bool {
+        self.counts.len() == self.groups.len()
+    }
+
+    fn solution(&self) -> Option<usize> {
+        self.with_done_some(self.sum)
+    }
+
+    fn step(&mut self) {
+        if self.is_done() {
+            return;
+        }
+
+        let count = self.groups[self.counts.len()].count::<P>();
+        self.counts.push(count);
+        self.sum += count;
+    }
+
+    fn draw(&self, printer: &Printer) {
+        let range = last_n(self.counts.len(), printer.size.y);
+        for (y, i) in range.enumerate() {
+            let answers: Vec<String> = self.groups[i]
+                .get_answers()
+                .iter()
+                .map(|answer| {
+                    let mut answer: Vec<_> = answer.iter().collect();
+                    answer.sort();
+                    answer.into_iter().collect()
+                })
+                .collect();
+            let line = format!("{} ... {}", answers.join(" "), self.counts[i]);
+            printer.print((0, y), &line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{solver::Solver, util::split_groups};
+    use crate::day06::group::{CorrectPolicy, Group, MisreadPolicy};
+
+    use super::GroupCounter;
+
+    const EXAMPLE: &str = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+
+    fn get_groups() -> Vec<Group> {
+        split_groups(EXAMPLE).iter().map(Group::new).collect()
+    }
+
+    #[test]
+    fn it_solves_the_example() {
+        let mut solver = GroupCounter::<MisreadPolicy>::new(get_groups());
+        assert_eq!(solver.solve(), Some(11));
+
+        let mut solver = GroupCounter::<CorrectPolicy>::new(get_groups());
+        assert_eq!(solver.solve(), Some(6));
+    }
+}
@@ -1 +1,51 @@
+pub mod counter;
 pub mod group;
+
+use counter::GroupCounter;
+use group::{CorrectPolicy, Group, MisreadPolicy};
+
+use crate::core::{
+    puzzle::{Puzzle, PuzzlePart, SolverResult},
+    solver::boxed,
+    util::split_groups,
+};
+
+#[derive(Debug, Default)]
+pub struct Day06 {}
+
+impl Day06 {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Puzzle for Day06 {
+    fn get_title(&self) -> String {
+        "Custom Customs".to_string()
+    }
+
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        6
+    }
+
+    fn get_input_path(&self) -> String {
+        "day06/customs_answers.txt".to_string()
+    }
+
+    fn is_implemented(&self, _part: PuzzlePart) -> bool {
+        true
+    }
+
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let groups = split_groups(input).iter().map(Group::new).collect();
+        if part == PuzzlePart::One {
+            Ok(boxed(GroupCounter::<MisreadPolicy>::new(groups)))
+        } else {
+            Ok(boxed(GroupCounter::<CorrectPolicy>::new(groups)))
+        }
+    }
+}
@@ -1,3 +1,53 @@
 pub mod part1;
 pub mod part2;
 pub mod rule;
+
+use part1::SolvePart1;
+use part2::SolvePart2;
+use rule::Rule;
+
+use crate::core::{
+    puzzle::{Puzzle, PuzzlePart, SolverResult},
+    solver::boxed,
+};
+
+#[derive(Debug, Default)]
+pub struct Day07 {}
+
+impl Day07 {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Puzzle for Day07 {
+    fn get_title(&self) -> String {
+        "Handy Haversacks".to_string()
+    }
+
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        7
+    }
+
+    fn get_input_path(&self) -> String {
+        "day07/rules.txt".to_string()
+    }
+
+    fn is_implemented(&self, _part: PuzzlePart) -> bool {
+        true
+    }
+
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let rules = Rule::parse_desc(input)?;
+        let color = "shiny gold".to_string();
+        if part == PuzzlePart::One {
+            Ok(boxed(SolvePart1::new(color, rules)))
+        } else {
+            Ok(boxed(SolvePart2::new(color, rules)))
+        }
+    }
+}
@@ -1,8 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::core::Solver;
+use crate::core::{util::last_n, Solver};
 
-use cursive::Printer;
+use cursive::{
+    theme::{ColorStyle, Style},
+    utils::span::SpannedStr,
+    utils::span::SpannedString,
+    Printer,
+};
 
 use super::rule::Rule;
 
@@ -62,8 +67,37 @@ impl Solver<i64> for SolvePart1 {
         }
     }
 
-    fn draw(&self, _printer: &Printer) {
-        todo!()
+    fn draw(&self, printer: &Printer) {
+        let header = format!(
+            "Bags that can contain {}: {}",
+            self.color,
+            self.containers.len()
+        );
+        printer.print((0, 0), &header);
+
+        let mut containers: Vec<_> = self.containers.iter().collect();
+        containers.sort();
+        let mut frontier: Vec<_> = self.frontier.iter().collect();
+        frontier.sort();
+
+        let lines: Vec<_> = containers
+            .into_iter()
+            .map(|color| (color, false))
+            .chain(frontier.into_iter().map(|color| (color, true)))
+            .collect();
+
+        let range = last_n(lines.len(), printer.size.y.saturating_sub(1));
+        for (y, &(color, searching)) in lines[range].iter().enumerate() {
+            if searching {
+                let styled = SpannedString::styled(
+                    format!("{} ... searching", color),
+                    Style::from(ColorStyle::tertiary()),
+                );
+                printer.print_styled((0, y + 1), SpannedStr::from(&styled));
+            } else {
+                printer.print((0, y + 1), color);
+            }
+        }
     }
 }
 
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use cursive::Printer;
 
-use crate::core::Solver;
+use crate::core::{util::last_n, Solver};
 
 use super::rule::Rule;
 
@@ -67,8 +67,18 @@ impl Solver<i64> for SolvePart2 {
         }
     }
 
-    fn draw(&self, _printer: &Printer) {
-        todo!()
+    fn draw(&self, printer: &Printer) {
+        let header = format!("Bags inside {}: {}", self.color, self.count);
+        printer.print((0, 0), &header);
+
+        let mut frontier: Vec<_> = self.frontier.iter().collect();
+        frontier.sort();
+
+        let range = last_n(frontier.len(), printer.size.y.saturating_sub(1));
+        for (y, (color, count)) in frontier[range].iter().enumerate() {
+            let line = format!("{} x {}", count, color);
+            printer.print((0, y + 1), &line);
+        }
     }
 }
 
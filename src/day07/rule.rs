@@ -1,5 +1,9 @@
 use std::{collections::HashMap, str::FromStr};
 
+use thiserror::Error;
+
+use crate::core::fs::{parse_str_lines, ParseIoResult};
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Rule {
     color: String,
@@ -73,8 +77,8 @@ impl Rule {
             .collect()
     }
 
-    pub fn parse_desc(desc: &str) -> Result<Vec<Rule>, RuleError> {
-        desc.lines().map(|s| s.parse()).collect()
+    pub fn parse_desc(desc: &str) -> ParseIoResult<Vec<Rule>> {
+        parse_str_lines(desc)
     }
 }
 
@@ -91,10 +95,15 @@ pub fn find_direct_containers(color: &str, rules: &[Rule]) -> Vec<String> {
         .collect()
 }
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum RuleError {
+    #[error("The rule is not of the form '<color> bags contain <bags>.'")]
     InvalidRule,
+
+    #[error("Could not parse the number of bags")]
     ParseBagCount,
+
+    #[error("The rule contains a bag that is not of the form '<count> <color> bags'")]
     InvalidBagCount,
 }
 
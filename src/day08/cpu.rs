@@ -1,3 +1,12 @@
+use std::collections::HashSet;
+
+use cursive::{
+    theme::{ColorStyle, Style},
+    utils::span::SpannedStr,
+    utils::span::SpannedString,
+    Printer,
+};
+
 use super::op::Operation;
 
 pub struct Cpu {
@@ -6,6 +15,28 @@ pub struct Cpu {
     acc: i64,
 }
 
+/// Draw the instructions surrounding the program counter, highlighting any
+/// that have already been executed
+pub fn draw_mem(cpu: &Cpu, executed: &HashSet<usize>, printer: &Printer) {
+    let header = format!("pc: {}  acc: {}", cpu.pc(), cpu.acc());
+    printer.print((0, 0), &header);
+
+    let height = printer.size.y.saturating_sub(1);
+    let begin = cpu.pc().saturating_sub(height / 2);
+    let end = (begin + height).min(cpu.mem().len());
+
+    for (y, i) in (begin..end).enumerate() {
+        let marker = if i == cpu.pc() { ">" } else { " " };
+        let line = format!("{} {:>4} {}", marker, i, cpu.mem()[i]);
+        if executed.contains(&i) {
+            let styled = SpannedString::styled(line, Style::from(ColorStyle::tertiary()));
+            printer.print_styled((0, y + 1), SpannedStr::from(&styled));
+        } else {
+            printer.print((0, y + 1), &line);
+        }
+    }
+}
+
 impl Cpu {
     pub fn new(mem: Vec<Operation>) -> Self {
         Self { mem, pc: 0, acc: 0 }
@@ -19,6 +50,10 @@ impl Cpu {
         self.acc
     }
 
+    pub fn mem(&self) -> &[Operation] {
+        &self.mem
+    }
+
     pub fn step(&mut self) {
         if let Some(&op) = self.fetch() {
             self.exec(op);
@@ -2,3 +2,52 @@ pub mod cpu;
 pub mod op;
 pub mod part1;
 pub mod part2;
+
+use op::Operation;
+use part1::SolvePart1;
+use part2::SolvePart2;
+
+use crate::core::{
+    puzzle::{Puzzle, PuzzlePart, SolverResult},
+    solver::boxed,
+};
+
+#[derive(Debug, Default)]
+pub struct Day08 {}
+
+impl Day08 {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Puzzle for Day08 {
+    fn get_title(&self) -> String {
+        "Handheld Halting".to_string()
+    }
+
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        8
+    }
+
+    fn get_input_path(&self) -> String {
+        "day08/boot_code.txt".to_string()
+    }
+
+    fn is_implemented(&self, _part: PuzzlePart) -> bool {
+        true
+    }
+
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let mem = Operation::parse_mem(input)?;
+        if part == PuzzlePart::One {
+            Ok(boxed(SolvePart1::new(mem)))
+        } else {
+            Ok(boxed(SolvePart2::new(mem)))
+        }
+    }
+}
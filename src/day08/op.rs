@@ -1,4 +1,8 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
+
+use thiserror::Error;
+
+use crate::core::fs::{parse_str_lines, ParseIoResult};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Operation {
@@ -8,15 +12,30 @@ pub enum Operation {
 }
 
 impl Operation {
-    pub fn parse_mem(contents: &str) -> Result<Vec<Operation>, OperationError> {
-        contents.lines().map(|line| line.parse()).collect()
+    pub fn parse_mem(contents: &str) -> ParseIoResult<Vec<Operation>> {
+        parse_str_lines(contents)
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Nop(x) => write!(f, "nop {:+}", x),
+            Operation::Acc(x) => write!(f, "acc {:+}", x),
+            Operation::Jmp(x) => write!(f, "jmp {:+}", x),
+        }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Error)]
 pub enum OperationError {
+    #[error("The operation is not of the form '<op> <argument>'")]
     BadSyntax,
+
+    #[error("Could not parse the argument of the operation")]
     BadArgument,
+
+    #[error("Unknown operation, expected nop, acc, or jmp")]
     BadOperation,
 }
 
@@ -2,7 +2,10 @@ use std::collections::HashSet;
 
 use crate::core::Solver;
 
-use super::{cpu::Cpu, op::Operation};
+use super::{
+    cpu::{draw_mem, Cpu},
+    op::Operation,
+};
 
 use cursive::Printer;
 
@@ -38,8 +41,8 @@ impl Solver<i64> for SolvePart1 {
         self.cpu.step();
     }
 
-    fn draw(&self, _printer: &Printer) {
-        todo!()
+    fn draw(&self, printer: &Printer) {
+        draw_mem(&self.cpu, &self.instructions, printer);
     }
 }
 
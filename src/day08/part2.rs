@@ -88,8 +88,26 @@ impl Solver<i64> for SolvePart2 {
         self.i += 1;
     }
 
-    fn draw(&self, _printer: &Printer) {
-        todo!()
+    fn draw(&self, printer: &Printer) {
+        let header = format!("Attempt {} of {}", self.i, self.candidates.len());
+        printer.print((0, 0), &header);
+
+        if let Some(cpu) = &self.cpu {
+            let flipped = self.candidates[self.i - 1];
+            let status = if self.terminated {
+                "terminated"
+            } else {
+                "looped"
+            };
+            let line = format!(
+                "Flipped instruction {} ({}) ... {} with acc {}",
+                flipped,
+                self.mem[flipped],
+                status,
+                cpu.acc()
+            );
+            printer.print((0, 1), &line);
+        }
     }
 }
 
@@ -1,2 +1,59 @@
 pub mod part1;
 pub mod part2;
+
+use part1::SolvePart1;
+use part2::SolvePart2;
+
+use crate::core::{
+    fs::parse_str_lines,
+    puzzle::{Puzzle, PuzzleError, PuzzlePart, SolverResult},
+    solver::{boxed, Solver},
+};
+
+const PREAMBLE: usize = 25;
+
+#[derive(Debug, Default)]
+pub struct Day09 {}
+
+impl Day09 {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Puzzle for Day09 {
+    fn get_title(&self) -> String {
+        "Encoding Error".to_string()
+    }
+
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        9
+    }
+
+    fn get_input_path(&self) -> String {
+        "day09/xmas.txt".to_string()
+    }
+
+    fn is_implemented(&self, _part: PuzzlePart) -> bool {
+        true
+    }
+
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let xmas: Vec<usize> = parse_str_lines(input)?;
+        if part == PuzzlePart::One {
+            Ok(boxed(SolvePart1::new(xmas, PREAMBLE)))
+        } else {
+            // The target for part 2 is the solution to part 1
+            let target = SolvePart1::new(xmas.clone(), PREAMBLE)
+                .solve()
+                .ok_or_else(|| {
+                    PuzzleError::Unsolvable("Could not find the invalid number".to_string())
+                })?;
+            Ok(boxed(SolvePart2::new(xmas, target)))
+        }
+    }
+}
@@ -1,6 +1,11 @@
 use std::collections::HashSet;
 
-use cursive::Printer;
+use cursive::{
+    theme::{ColorStyle, Style},
+    utils::span::SpannedStr,
+    utils::span::SpannedString,
+    Printer,
+};
 
 use crate::core::Solver;
 
@@ -62,15 +67,46 @@ impl Solver<usize> for SolvePart1 {
         self.i += 1;
     }
 
-    fn draw(&self, _printer: &Printer) {
-        todo!()
+    fn draw(&self, printer: &Printer) {
+        if self.i == self.preamble {
+            return;
+        }
+
+        let current = self.i - 1;
+        for (y, j) in (current - self.preamble..current).enumerate() {
+            printer.print((0, y), &self.xmas[j].to_string());
+        }
+
+        let x = self.xmas[current];
+        let styled = if self.found == Some(x) {
+            SpannedString::styled(
+                format!("{} ... Not a sum!", x),
+                Style::from(ColorStyle::tertiary()),
+            )
+        } else {
+            SpannedString::styled(
+                format!("{} ... Valid", x),
+                Style::from(ColorStyle::secondary()),
+            )
+        };
+        printer.print_styled((0, self.preamble), SpannedStr::from(&styled));
+    }
+
+    fn steps_taken(&self) -> Option<usize> {
+        Some(self.i - self.preamble)
+    }
+
+    fn estimated_steps(&self) -> Option<usize> {
+        Some(self.xmas.len().saturating_sub(self.preamble))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use cursive::Vec2;
+
     use super::SolvePart1;
-    use crate::core::Solver;
+    use crate::core::{offscreen::render_solver, Solver};
 
     #[test]
     fn it_solves_the_example() {
@@ -81,4 +117,35 @@ mod tests {
         let mut solver = SolvePart1::new(xmas, 5);
         assert_eq!(solver.solve(), Some(127));
     }
+
+    #[test]
+    fn it_reports_progress() {
+        let xmas = (1..=10).collect();
+        let mut solver = SolvePart1::new(xmas, 5);
+        assert_eq!(solver.estimated_steps(), Some(5));
+        assert_eq!(solver.progress(), Some(0.0));
+
+        solver.step();
+        solver.step();
+        assert_eq!(solver.steps_taken(), Some(2));
+        assert_eq!(solver.progress(), Some(0.4));
+    }
+
+    #[test]
+    fn it_draws_the_window_and_the_current_number() {
+        let mut solver = SolvePart1::new(vec![1, 2, 3, 4, 9], 3);
+        solver.step();
+
+        let frame = render_solver(&solver, Vec2::new(20, 5));
+        assert_eq!(frame.lines(), vec!["1", "2", "3", "4 ... Valid", ""]);
+        assert_eq!(frame.style_lines(), vec!["", "", "", "sssssssssss", ""]);
+
+        solver.solve();
+        let frame = render_solver(&solver, Vec2::new(20, 5));
+        assert_eq!(frame.lines(), vec!["2", "3", "4", "9 ... Not a sum!", ""]);
+        assert_eq!(
+            frame.style_lines(),
+            vec!["", "", "", "tttttttttttttttt", ""]
+        );
+    }
 }
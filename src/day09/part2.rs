@@ -54,8 +54,18 @@ impl Solver<usize> for SolvePart2 {
         self.i += 1;
     }
 
-    fn draw(&self, _printer: &Printer) {
-        todo!()
+    fn draw(&self, printer: &Printer) {
+        let header = format!("Searching for a contiguous set summing to {}", self.target);
+        printer.print((0, 0), &header);
+
+        let line = match self.found {
+            Some((low, high)) => format!(
+                "Found a set starting at {} with min {} and max {}",
+                self.i, low, high
+            ),
+            None => format!("Checking sets starting at {}", self.i),
+        };
+        printer.print((0, 1), &line);
     }
 }
 
@@ -1,2 +1,51 @@
 pub mod part1;
 pub mod part2;
+
+use part1::SolvePart1;
+use part2::SolvePart2;
+
+use crate::core::{
+    fs::parse_str_lines,
+    puzzle::{Puzzle, PuzzlePart, SolverResult},
+    solver::boxed,
+};
+
+#[derive(Debug, Default)]
+pub struct Day10 {}
+
+impl Day10 {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Puzzle for Day10 {
+    fn get_title(&self) -> String {
+        "Adapter Array".to_string()
+    }
+
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        10
+    }
+
+    fn get_input_path(&self) -> String {
+        "day10/adapters.txt".to_string()
+    }
+
+    fn is_implemented(&self, _part: PuzzlePart) -> bool {
+        true
+    }
+
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let chargers: Vec<usize> = parse_str_lines(input)?;
+        if part == PuzzlePart::One {
+            Ok(boxed(SolvePart1::new(chargers)))
+        } else {
+            Ok(boxed(SolvePart2::new(chargers)))
+        }
+    }
+}
@@ -1,6 +1,10 @@
-use crate::core::Solver;
+use crate::core::{util::last_n, Solver};
 
-use cursive::Printer;
+use cursive::{
+    theme::{ColorStyle, Style},
+    utils::span::{SpannedStr, SpannedString},
+    Printer,
+};
 
 pub struct SolvePart1 {
     chargers: Vec<usize>,
@@ -12,9 +16,11 @@ pub struct SolvePart1 {
 
 impl SolvePart1 {
     pub fn new(chargers: Vec<usize>) -> Self {
+        // A list of at most one adapter is already sorted
+        let sorted = chargers.len() <= 1;
         Self {
             chargers,
-            sorted: false,
+            sorted,
             i: 1,
             num_1s: 1,
             num_3s: 1,
@@ -51,16 +57,63 @@ impl Solver<usize> for SolvePart1 {
         self.i += 1;
     }
 
-    fn draw(&self, _printer: &Printer) {
-        todo!()
+    fn draw(&self, printer: &Printer) {
+        if !self.sorted {
+            let line = format!("Sorting {} adapters...", self.chargers.len());
+            printer.print((0, 0), &line);
+            return;
+        }
+
+        let summary = format!(
+            "1-jolt differences: {}  3-jolt differences: {}",
+            self.num_1s, self.num_3s
+        );
+        printer.print((0, 0), &summary);
+
+        // The device is always 3 jolts above the highest adapter
+        let device = self.chargers.last().unwrap_or(&0) + 3;
+        let num_rows = self.i.min(self.chargers.len()) + self.is_done() as usize;
+        let range = last_n(num_rows, printer.size.y.saturating_sub(1));
+        for (y, j) in range.enumerate() {
+            let (label, jolts) = match self.chargers.get(j) {
+                Some(&jolts) if j < self.i => ("adapter", jolts),
+                _ => ("device", device),
+            };
+            let prev = if j == 0 { 0 } else { self.chargers[j - 1] };
+            let diff = jolts - prev;
+
+            let mut styled = SpannedString::<Style>::plain(format!("{:>7} {:>4} ", label, jolts));
+            let text = format!("+{}", diff);
+            match diff {
+                1 => styled.append_styled(text, Style::from(ColorStyle::secondary())),
+                3 => styled.append_styled(text, Style::from(ColorStyle::tertiary())),
+                _ => styled.append_plain(text),
+            }
+            printer.print_styled((0, y + 1), SpannedStr::from(&styled));
+        }
+    }
+
+    fn steps_taken(&self) -> Option<usize> {
+        let sort_steps = (self.sorted && self.chargers.len() > 1) as usize;
+        Some(sort_steps + self.i - 1)
+    }
+
+    fn estimated_steps(&self) -> Option<usize> {
+        // One step to sort, and then one for each difference
+        match self.chargers.len() {
+            0 | 1 => Some(0),
+            len => Some(len),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use cursive::Vec2;
+
     use super::SolvePart1;
 
-    use crate::core::Solver;
+    use crate::core::{offscreen::render_solver, Solver};
 
     #[test]
     fn it_solves_the_simple_example() {
@@ -78,4 +131,56 @@ mod tests {
         let mut solver = SolvePart1::new(chargers);
         assert_eq!(solver.solve(), Some(22 * 10));
     }
+
+    #[test]
+    fn it_draws_the_adapter_chain() {
+        let mut solver = SolvePart1::new(vec![4, 1, 5, 8]);
+        let frame = render_solver(&solver, Vec2::new(50, 6));
+        assert_eq!(frame.text(), "Sorting 4 adapters...");
+
+        solver.solve();
+        let frame = render_solver(&solver, Vec2::new(50, 6));
+        assert_eq!(
+            frame.lines(),
+            vec![
+                "1-jolt differences: 2  3-jolt differences: 3",
+                "adapter    1 +1",
+                "adapter    4 +3",
+                "adapter    5 +1",
+                "adapter    8 +3",
+                " device   11 +3",
+            ]
+        );
+        assert_eq!(
+            frame.style_lines(),
+            vec![
+                "",
+                "             ss",
+                "             tt",
+                "             ss",
+                "             tt",
+                "             tt",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_draws_a_single_adapter() {
+        let mut solver = SolvePart1::new(vec![2]);
+        assert!(solver.is_done());
+        assert_eq!(solver.steps_taken(), Some(0));
+        assert_eq!(solver.estimated_steps(), Some(0));
+
+        solver.solve();
+        let frame = render_solver(&solver, Vec2::new(50, 4));
+        assert_eq!(
+            frame.lines(),
+            vec![
+                "1-jolt differences: 1  3-jolt differences: 1",
+                "adapter    2 +2",
+                " device    5 +3",
+                "",
+            ]
+        );
+    }
 }
@@ -0,0 +1,150 @@
+use crate::core::{
+    solver::{snapshot_of, Solver},
+    util::last_n,
+};
+
+use cursive::{
+    theme::{ColorStyle, Style},
+    utils::span::{SpannedStr, SpannedString},
+    Printer,
+};
+
+/// The largest difference in joltage that an adapter can take
+const MAX_DIFF: usize = 3;
+
+/// Counts the ways to arrange the adapters by filling in a table where each
+/// entry is the number of ways to reach that adapter from the outlet
+#[derive(Debug, Clone)]
+pub struct SolvePart2 {
+    /// The outlet followed by the sorted adapters
+    jolts: Vec<usize>,
+    ways: Vec<u64>,
+}
+
+impl SolvePart2 {
+    pub fn new(mut chargers: Vec<usize>) -> Self {
+        chargers.sort_unstable();
+
+        let mut jolts = vec![0];
+        jolts.extend(chargers);
+
+        Self {
+            jolts,
+            ways: vec![1],
+        }
+    }
+
+    /// The adapters that can connect directly to the adapter at index k
+    fn sources(&self, k: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..k)
+            .rev()
+            .take_while(move |&j| self.jolts[k] - self.jolts[j] <= MAX_DIFF)
+    }
+}
+
+impl Solver<u64> for SolvePart2 {
+    fn is_done(&self) -> bool {
+        self.ways.len() >= self.jolts.len()
+    }
+
+    fn solution(&self) -> Option<u64> {
+        self.with_done(|| *self.ways.last().unwrap())
+    }
+
+    fn step(&mut self) {
+        if self.is_done() {
+            return;
+        }
+
+        let k = self.ways.len();
+        let ways = self.sources(k).map(|j| self.ways[j]).sum();
+        self.ways.push(ways);
+    }
+
+    fn draw(&self, printer: &Printer) {
+        printer.print((0, 0), "Jolts  Arrangements");
+
+        let current = self.ways.len() - 1;
+        let sources: Vec<_> = if current == 0 {
+            Vec::new()
+        } else {
+            self.sources(current).collect()
+        };
+
+        let range = last_n(self.ways.len(), printer.size.y.saturating_sub(1));
+        for (y, k) in range.enumerate() {
+            let text = format!("{:>5}  {}", self.jolts[k], self.ways[k]);
+            let styled = if k == current {
+                SpannedString::styled(text, Style::from(ColorStyle::secondary()))
+            } else if sources.contains(&k) {
+                SpannedString::styled(text, Style::from(ColorStyle::tertiary()))
+            } else {
+                SpannedString::<Style>::plain(text)
+            };
+            printer.print_styled((0, y + 1), SpannedStr::from(&styled));
+        }
+    }
+
+    fn steps_taken(&self) -> Option<usize> {
+        Some(self.ways.len() - 1)
+    }
+
+    fn estimated_steps(&self) -> Option<usize> {
+        Some(self.jolts.len() - 1)
+    }
+
+    fn snapshot(&self) -> Option<Box<dyn Solver<u64>>> {
+        snapshot_of(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cursive::Vec2;
+
+    use super::SolvePart2;
+
+    use crate::core::{offscreen::render_solver, Solver};
+
+    #[test]
+    fn it_solves_the_simple_example() {
+        let chargers = vec![16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4];
+        let mut solver = SolvePart2::new(chargers);
+        assert_eq!(solver.solve(), Some(8));
+    }
+
+    #[test]
+    fn it_solves_the_larger_example() {
+        let chargers = vec![
+            28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25, 35,
+            8, 17, 7, 9, 4, 2, 34, 10, 3,
+        ];
+        let mut solver = SolvePart2::new(chargers);
+        assert_eq!(solver.solve(), Some(19208));
+    }
+
+    #[test]
+    fn it_draws_the_table_filling_in() {
+        let mut solver = SolvePart2::new(vec![3, 1, 2, 5]);
+        solver.step();
+        solver.step();
+        solver.step();
+
+        let frame = render_solver(&solver, Vec2::new(20, 5));
+        assert_eq!(
+            frame.lines(),
+            vec![
+                "Jolts  Arrangements",
+                "    0  1",
+                "    1  1",
+                "    2  2",
+                "    3  4"
+            ]
+        );
+        assert_eq!(
+            frame.style_lines(),
+            vec!["", "tttttttt", "tttttttt", "tttttttt", "ssssssss"]
+        );
+        assert_eq!(solver.progress(), Some(0.75));
+    }
+}
@@ -1 +1,51 @@
 pub mod model;
+pub mod simulation;
+
+use model::{AdjacentPolicy, VisiblePolicy};
+use simulation::SeatingSimulation;
+
+use crate::core::{
+    grid::Grid,
+    puzzle::{Puzzle, PuzzlePart, SolverResult},
+    solver::boxed,
+};
+
+#[derive(Debug, Default)]
+pub struct Day11 {}
+
+impl Day11 {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Puzzle for Day11 {
+    fn get_title(&self) -> String {
+        "Seating System".to_string()
+    }
+
+    fn get_year(&self) -> usize {
+        2020
+    }
+
+    fn get_day(&self) -> usize {
+        11
+    }
+
+    fn get_input_path(&self) -> String {
+        "day11/seats.txt".to_string()
+    }
+
+    fn is_implemented(&self, _part: PuzzlePart) -> bool {
+        true
+    }
+
+    fn solver(&self, part: PuzzlePart, input: &str) -> SolverResult {
+        let seats: Grid<char> = input.parse()?;
+        if part == PuzzlePart::One {
+            Ok(boxed(SeatingSimulation::<AdjacentPolicy>::new(seats)))
+        } else {
+            Ok(boxed(SeatingSimulation::<VisiblePolicy>::new(seats)))
+        }
+    }
+}
@@ -1,15 +1,61 @@
-use crate::core::Grid;
+use std::marker::PhantomData;
 
-pub struct SeatingModel {
+use crate::core::{grid::Neighborhood, Grid};
+
+/// Decides which occupied seats a passenger pays attention to
+pub trait SeatingPolicy {
+    /// The number of occupied seats that makes a passenger leave their seat
+    const TOLERANCE: usize;
+
+    fn num_occupied(seats: &Grid<char>, x: usize, y: usize) -> usize;
+}
+
+/// Passengers only look at the seats next to them
+pub struct AdjacentPolicy {}
+
+impl SeatingPolicy for AdjacentPolicy {
+    const TOLERANCE: usize = 4;
+
+    fn num_occupied(seats: &Grid<char>, x: usize, y: usize) -> usize {
+        seats
+            .neighbors(x, y)
+            .unwrap()
+            .into_iter()
+            .filter(|&&c| c == '#')
+            .count()
+    }
+}
+
+/// Passengers look at the first seat they can see in each direction
+pub struct VisiblePolicy {}
+
+impl SeatingPolicy for VisiblePolicy {
+    const TOLERANCE: usize = 5;
+
+    fn num_occupied(seats: &Grid<char>, x: usize, y: usize) -> usize {
+        seats
+            .cast_rays(x, y, &Neighborhood::Moore, |&c| c != '.')
+            .into_iter()
+            .filter(|&(_, &c)| c == '#')
+            .count()
+    }
+}
+
+pub struct SeatingModel<P: SeatingPolicy> {
     seats: Grid<char>,
     stable: bool,
+    _phantom: PhantomData<P>,
 }
 
-impl SeatingModel {
+impl<P> SeatingModel<P>
+where
+    P: SeatingPolicy,
+{
     pub fn new(seats: Grid<char>) -> Self {
         Self {
             seats,
             stable: false,
+            _phantom: PhantomData,
         }
     }
 
@@ -25,26 +71,23 @@ impl SeatingModel {
         let mut next = self.seats.clone();
 
         let mut changed = false;
-        for y in 0..self.seats.height() {
-            for x in 0..self.seats.width() {
-                let &prev_seat = self.seats.get(x, y).unwrap();
-                if prev_seat == '.' {
-                    continue;
-                }
-
-                let num_occupied = self.num_occupied_neighbors(x, y);
-                let seat = if prev_seat == 'L' && num_occupied == 0 {
-                    '#'
-                } else if prev_seat == '#' && num_occupied >= 4 {
-                    'L'
-                } else {
-                    prev_seat
-                };
-
-                if seat != prev_seat {
-                    next.set(x, y, seat);
-                    changed = true;
-                }
+        for ((x, y), &prev_seat) in self.seats.enumerate() {
+            if prev_seat == '.' {
+                continue;
+            }
+
+            let num_occupied = P::num_occupied(&self.seats, x, y);
+            let seat = if prev_seat == 'L' && num_occupied == 0 {
+                '#'
+            } else if prev_seat == '#' && num_occupied >= P::TOLERANCE {
+                'L'
+            } else {
+                prev_seat
+            };
+
+            if seat != prev_seat {
+                next[(x, y)] = seat;
+                changed = true;
             }
         }
 
@@ -61,20 +104,11 @@ impl SeatingModel {
         }
         self.seats()
     }
-
-    fn num_occupied_neighbors(&self, x: usize, y: usize) -> usize {
-        self.seats
-            .neighbors(x, y)
-            .unwrap()
-            .into_iter()
-            .filter(|&&c| c == '#')
-            .count()
-    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::SeatingModel;
+    use super::{AdjacentPolicy, SeatingModel, VisiblePolicy};
 
     const EXAMPLE: &str = "L.LL.LL.LL
 LLLLLLL.LL
@@ -98,11 +132,30 @@ L.#.L..#..
 #.LLLLLL.L
 #.#L#L#.##";
 
+    const FINAL_VISIBLE: &str = "#.L#.L#.L#
+#LLLLLL.LL
+L.L.L..#..
+##L#.#L.L#
+L.L#.LL.L#
+#.LLLL#.LL
+..#.L.....
+LLL###LLL#
+#.LLLLL#.L
+#.L#LL#.L#";
+
     #[test]
     fn it_models_the_example() {
         let seats = EXAMPLE.parse().unwrap();
         let expected = FINAL.parse().unwrap();
-        let mut model = SeatingModel::new(seats);
+        let mut model = SeatingModel::<AdjacentPolicy>::new(seats);
+        assert_eq!(model.simulate(), &expected);
+    }
+
+    #[test]
+    fn it_models_the_example_with_visible_seats() {
+        let seats = EXAMPLE.parse().unwrap();
+        let expected = FINAL_VISIBLE.parse().unwrap();
+        let mut model = SeatingModel::<VisiblePolicy>::new(seats);
         assert_eq!(model.simulate(), &expected);
     }
 }
@@ -0,0 +1,87 @@
+use cursive::{
+    theme::{ColorStyle, Style},
+    utils::span::SpannedStr,
+    utils::span::SpannedString,
+    Printer,
+};
+
+use crate::core::{Grid, Solver};
+
+use super::model::{SeatingModel, SeatingPolicy};
+
+/// Shuffles passengers between seats according to a seating policy until
+/// nobody moves, then counts the occupied seats
+pub struct SeatingSimulation<P: SeatingPolicy> {
+    model: SeatingModel<P>,
+}
+
+impl<P> SeatingSimulation<P>
+where
+    P: SeatingPolicy,
+{
+    pub fn new(seats: Grid<char>) -> Self {
+        Self {
+            model: SeatingModel::new(seats),
+        }
+    }
+}
+
+impl<P> Solver<usize> for SeatingSimulation<P>
+where
+    P: SeatingPolicy,
+{
+    fn is_done(&self) -> bool {
+        self.model.is_stable()
+    }
+
+    fn solution(&self) -> Option<usize> {
+        self.with_done(|| self.model.seats().cells().filter(|&&c| c == '#').count())
+    }
+
+    fn step(&mut self) {
+        self.model.step();
+    }
+
+    fn draw(&self, printer: &Printer) {
+        let occupied = Style::from(ColorStyle::secondary());
+        for (y, row) in self.model.seats().rows().enumerate() {
+            let mut styled = SpannedString::<Style>::new();
+            for &c in row {
+                if c == '#' {
+                    styled.append_styled(c.to_string(), occupied);
+                } else {
+                    styled.append_plain(c.to_string());
+                }
+            }
+            printer.print_styled((0, y), SpannedStr::from(&styled));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::Solver;
+    use crate::day11::model::{AdjacentPolicy, VisiblePolicy};
+
+    use super::SeatingSimulation;
+
+    const EXAMPLE: &str = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+    #[test]
+    fn it_solves_the_example() {
+        let mut solver = SeatingSimulation::<AdjacentPolicy>::new(EXAMPLE.parse().unwrap());
+        assert_eq!(solver.solve(), Some(37));
+
+        let mut solver = SeatingSimulation::<VisiblePolicy>::new(EXAMPLE.parse().unwrap());
+        assert_eq!(solver.solve(), Some(26));
+    }
+}
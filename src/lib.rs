@@ -1,4 +1,5 @@
 pub mod core;
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -10,3 +11,30 @@ pub mod day08;
 pub mod day09;
 pub mod day10;
 pub mod day11;
+
+/// Build the list of puzzles from each day's module and the puzzle it exports,
+/// so that adding a day to this list is all it takes for the puzzle to be
+/// registered
+macro_rules! puzzles {
+    ($($day:ident::$puzzle:ident),* $(,)?) => {
+        /// Every puzzle in the crate. Each puzzle declares its own day, so the
+        /// order of this list does not matter.
+        pub fn puzzles() -> Vec<Box<dyn core::puzzle::Puzzle>> {
+            vec![$(Box::new($day::$puzzle::new())),*]
+        }
+    };
+}
+
+puzzles! {
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+}
@@ -1,30 +1,192 @@
-use std::{cell::RefCell, rc::Rc};
-
-use advent::day01::Day01;
-use advent::day02::Day02;
-use advent::{
-    core::{
-        controller::run,
-        hub::{Hub, HubEvent},
-        puzzle::{Puzzle, PuzzleRegistry},
+use std::{
+    cell::RefCell,
+    env,
+    fmt::Display,
+    fs::File,
+    path::{Path, PathBuf},
+    process,
+    rc::Rc,
+};
+
+use advent::core::{
+    answers::{AnswerSheet, ANSWERS_FILE},
+    bench::format_results,
+    cli::{
+        parse_args, run_bench, run_capture, run_fetch, run_headless_reporting, run_record,
+        run_submit, Command, USAGE,
     },
-    day03::Day03,
+    controller::run,
+    hub::{Hub, HubEvent},
+    input::{root_from_env, InputProvider},
+    puzzle::PuzzleRegistry,
+    recording::write_recording,
+    remote::{session_from_env, Client, CurlBackend},
+    results::{ResultLog, RESULTS_FILE},
 };
 
-const NUM_DAYS: usize = 26;
+const NUM_DAYS: usize = 25;
 
 fn main() {
+    let command = parse_args(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{}\n\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    let registry = PuzzleRegistry::with_puzzles(NUM_DAYS, advent::puzzles())
+        .expect("Failed to register puzzles");
+
+    match command {
+        Command::Hub { input } => {
+            let input = input.unwrap_or_else(InputProvider::from_env);
+            run_hub(registry, input);
+        }
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => {
+            let input = input.unwrap_or_else(InputProvider::from_env);
+            let solution = run_headless_reporting(&registry, &input, year, day, part, &mut |p| {
+                eprintln!("{}", p)
+            })
+            .unwrap_or_else(fail);
+            println!("{}", solution);
+        }
+        Command::Fetch {
+            year,
+            day,
+            input_dir,
+        } => {
+            let client = remote_client(input_dir);
+            let path = run_fetch(&registry, &client, year, day).unwrap_or_else(fail);
+            println!("{}", path.display());
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            input_dir,
+        } => {
+            let client = remote_client(input_dir);
+            let (answer, verdict) =
+                run_submit(&registry, &client, year, day, part, answer).unwrap_or_else(fail);
+            println!("{}: {}", answer, verdict);
+
+            if verdict.is_correct() {
+                let mut sheet = open_answer_sheet();
+                run_record(&registry, &mut sheet, year, day, part, answer).unwrap_or_else(fail);
+            }
+        }
+        Command::Record {
+            year,
+            day,
+            part,
+            answer,
+        } => {
+            let mut sheet = open_answer_sheet();
+            let answer =
+                run_record(&registry, &mut sheet, year, day, part, answer).unwrap_or_else(fail);
+            println!("{}", answer);
+        }
+        Command::Bench {
+            year,
+            day,
+            part,
+            runs,
+            format,
+            input,
+        } => {
+            let input = input.unwrap_or_else(InputProvider::from_env);
+            let (results, skipped) =
+                run_bench(&registry, &input, year, day, part, runs).unwrap_or_else(fail);
+            for err in skipped {
+                eprintln!("Skipped: {}", err);
+            }
+            println!("{}", format_results(&results, format));
+        }
+        Command::Capture {
+            year,
+            day,
+            part,
+            input,
+            output,
+            format,
+            options,
+        } => {
+            let input = input.unwrap_or_else(InputProvider::from_env);
+            let (title, frames) =
+                run_capture(&registry, &input, year, day, part, &options).unwrap_or_else(fail);
+
+            let mut file = File::create(&output).unwrap_or_else(fail);
+            write_recording(&mut file, &frames, &options, format, &title).unwrap_or_else(fail);
+            println!("Recorded {} frames to {}", frames.len(), output.display());
+        }
+    }
+}
+
+fn fail<T, E: Display>(err: E) -> T {
+    eprintln!("{}", err);
+    process::exit(1);
+}
+
+fn open_answer_sheet() -> AnswerSheet {
+    AnswerSheet::open(Path::new(ANSWERS_FILE)).unwrap_or_else(fail)
+}
+
+fn remote_client(input_dir: Option<PathBuf>) -> Client<CurlBackend> {
+    let session = session_from_env().unwrap_or_else(fail);
+    let root = input_dir.unwrap_or_else(root_from_env);
+    Client::new(CurlBackend::new(), session, root)
+}
+
+/// Open the log of past results, along with a description of anything that
+/// could not be read. A log that cannot be read at all is treated as empty.
+fn open_results(path: &Path) -> (ResultLog, Option<String>) {
+    match ResultLog::open(path) {
+        Ok(results) => {
+            let error = match results.malformed_lines() {
+                [] => None,
+                lines => Some(format!(
+                    "Skipped {} malformed line(s) of {}: {}",
+                    lines.len(),
+                    path.display(),
+                    lines
+                        .iter()
+                        .map(|line| line.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            };
+            (results, error)
+        }
+        Err(err) => {
+            let error = format!(
+                "Could not read {}: {}. Past results will not be shown.",
+                path.display(),
+                err
+            );
+            (ResultLog::empty(path), Some(error))
+        }
+    }
+}
+
+fn run_hub(registry: PuzzleRegistry, input: InputProvider) {
     let c = Rc::new(RefCell::new(cursive::default()));
     c.borrow_mut().set_fps(30);
 
-    let puzzles: Vec<Box<dyn Puzzle>> = vec![
-        Box::new(Day01::new()),
-        Box::new(Day02::new()),
-        Box::new(Day03::new()),
-    ];
-    let registry =
-        PuzzleRegistry::with_puzzles(NUM_DAYS, puzzles).expect("Failed to register puzzles");
+    // Keep results alongside the inputs, even when the input is read from
+    // elsewhere
+    let root = match &input {
+        InputProvider::Root(root) => root.clone(),
+        _ => root_from_env(),
+    };
+    let (results, error) = open_results(&root.join(RESULTS_FILE));
 
-    let hub = Hub::new(registry);
+    let mut hub = Hub::new(registry, input, results);
+    if let Some(error) = error {
+        hub = hub.with_startup_error(error);
+    }
     run::<Hub, HubEvent>(hub, Rc::clone(&c));
 }
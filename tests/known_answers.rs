@@ -0,0 +1,83 @@
+//! Solves every implemented part of every registered puzzle and checks that
+//! the solution matches the verified answer in the answer sheet. Parts whose
+//! input or verified answer is missing are skipped and listed, so the suite
+//! passes on checkouts without puzzle inputs or a filled-in answer sheet.
+
+use std::path::Path;
+
+use advent::core::{
+    answers::{AnswerSheet, ANSWERS_FILE},
+    cli::run_headless,
+    input::InputProvider,
+    puzzle::{PuzzlePart, PuzzleRegistry},
+};
+
+const NUM_DAYS: usize = 25;
+
+#[test]
+fn solutions_match_known_answers() {
+    let registry = PuzzleRegistry::with_puzzles(NUM_DAYS, advent::puzzles()).unwrap();
+    let sheet = AnswerSheet::open(&Path::new(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_FILE))
+        .expect("Could not read answer sheet");
+    let input = InputProvider::from_env();
+
+    let puzzles = registry
+        .get_years()
+        .into_iter()
+        .filter_map(|year| registry.get_puzzles(year))
+        .flatten()
+        .filter_map(|slot| slot.as_deref());
+
+    let mut checked = 0;
+    let mut skipped = Vec::new();
+    let mut failures = Vec::new();
+    for puzzle in puzzles {
+        let (year, day) = (puzzle.get_year(), puzzle.get_day());
+        for &part in [PuzzlePart::One, PuzzlePart::Two].iter() {
+            if !puzzle.is_implemented(part) {
+                continue;
+            }
+
+            if let Some(path) = input.locate(puzzle) {
+                if !path.exists() {
+                    skipped.push(format!(
+                        "{} day {} part {}: no input at {}",
+                        year,
+                        day,
+                        part,
+                        path.display()
+                    ));
+                    continue;
+                }
+            }
+
+            let expected = match sheet.get(year, day, part) {
+                Some(expected) => expected,
+                None => {
+                    skipped.push(format!(
+                        "{} day {} part {}: no verified answer in {}",
+                        year, day, part, ANSWERS_FILE
+                    ));
+                    continue;
+                }
+            };
+
+            checked += 1;
+            match run_headless(&registry, &input, Some(year), day, part) {
+                Ok(actual) if actual == expected => (),
+                Ok(actual) => failures.push(format!(
+                    "{} day {} part {}: expected {}, got {}",
+                    year, day, part, expected, actual
+                )),
+                Err(err) => failures.push(format!("{} day {} part {}: {}", year, day, part, err)),
+            }
+        }
+    }
+
+    println!("checked {} parts against known answers", checked);
+    for skip in &skipped {
+        println!("skipped {}", skip);
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}